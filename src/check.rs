@@ -0,0 +1,65 @@
+
+// uses
+use crate::table::TruthTable;
+use crate::value::Value;
+
+/**
+ * what a column is true for, leaving out the rows it doesn't care about
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Tautology,
+    Contradiction,
+
+    // true for some assignments and false for others
+    Satisfiable
+}
+
+/**
+ * the values of the input columns in a row
+ */
+pub type Assignment = Vec<(String, bool)>;
+
+/**
+ * the result of checking a column
+ */
+#[derive(Debug, Clone, PartialEq)]
+pub struct Check {
+    pub column: String,
+    pub verdict: Verdict,
+
+    // the first assignment the column is true for
+    pub witness: Option<Assignment>,
+
+    // the first assignment the column is false for
+    pub counterexample: Option<Assignment>
+}
+
+/**
+ * gets the values of the input columns in a row
+ */
+pub fn assignment(table: &TruthTable, row: &[Value]) -> Assignment {
+    table.columns.iter().zip(row)
+        .filter(|(column, _)| column.is_input())
+        .map(|(column, val)| (column.name.clone(), val.is_true()))
+        .collect()
+}
+
+/**
+ * checks whether a column is a tautology, a contradiction or satisfiable
+ */
+pub fn check(table: &TruthTable, column: usize) -> Check {
+    let witness = table.rows.iter().find(|row| row[column].is_true()).map(|row| assignment(table, row));
+    let counterexample = table.rows.iter().find(|row| row[column].is_false()).map(|row| assignment(table, row));
+    let verdict = match (&witness, &counterexample) {
+        (_, None) => Verdict::Tautology,
+        (None, Some(_)) => Verdict::Contradiction,
+        (Some(_), Some(_)) => Verdict::Satisfiable
+    };
+    return Check {
+        column: table.columns[column].name.clone(),
+        verdict,
+        witness,
+        counterexample
+    };
+}
@@ -0,0 +1,448 @@
+
+// uses
+use ttable::util::format::Format;
+use ttable::util::format::latex::LatexOptions;
+use ttable::util::symbols::Symbols;
+use ttable::util::tokenize::Precedence;
+use ttable::Value;
+
+/**
+ * enum for the input method
+ */
+pub enum In {
+    Cli(String),
+    Stdin,
+    File(String)
+}
+
+/**
+ * when to colour output
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Color {
+    Auto,
+    Always,
+    Never
+}
+
+/**
+ * what to do with the script
+ */
+pub enum Mode {
+    Table,
+
+    // check whether a column is a tautology, contradiction or satisfiable
+    Check(String),
+
+    // check whether the script is equivalent to another
+    Equiv(In),
+
+    // write the canonical normal forms of a column
+    Dnf(String),
+    Cnf(String),
+
+    // count the rows
+    Count,
+
+    // minimize a column
+    Minimize(String),
+
+    // draw the karnaugh map of a column
+    Kmap(String)
+}
+
+/**
+ * options for generating a table
+ */
+pub struct Options {
+    pub mode: Mode,
+    pub input: In,
+
+    // a column whose true rows are don't cares when minimizing
+    pub dont_care: Option<String>,
+
+    // highlight the groups of a minimal cover on karnaugh maps
+    pub groups: bool,
+
+    // inputs pinned to a value instead of being enumerated
+    pub pins: Vec<(String, bool)>,
+
+    // an expression rows must be true for to be written or counted
+    pub filter: Option<String>,
+
+    // the columns to write, instead of every column that is not hidden
+    pub columns: Option<Vec<String>>,
+
+    // how operators rank in scripts without a pragma for it
+    pub precedence: Precedence,
+
+    // reject different operators mixed without parentheses
+    pub strict: bool,
+
+    // skip unknown characters in scripts instead of failing
+    pub lenient: bool,
+    pub output: Option<String>,
+    pub format: Format,
+    pub symbols: Symbols,
+    pub latex: LatexOptions,
+    pub color: Color
+}
+
+/**
+ * what the command line asks for
+ */
+pub enum Command {
+    Run(Box<Options>),
+    Help,
+    Version
+}
+
+/**
+ * the help text for the application
+ */
+pub const HELP: &str = "\
+Generates truth tables from ttable scripts.
+
+Usage: ttable [OPTIONS] [FILE]
+
+Reads the script from FILE, or from stdin if no FILE is given or FILE is -.
+
+Options:
+  -c, --command <SCRIPT>  Read the script from SCRIPT instead of a file
+  -o, --output <FILE>     Write the table to FILE instead of stdout
+  -f, --format <FORMAT>   Write the table as FORMAT: text (default), csv,
+                          markdown, latex, json or pretty
+  -s, --symbols <SYMBOLS>  Write and read true and false as SYMBOLS: digits
+                          (1/0, default), letters (T/F), logic (⊤/⊥),
+                          levels (H/L) or a pair such as yes,no, with an
+                          optional don't care such as yes,no,maybe (X by
+                          default, and scripts may always write -)
+      --set <NAME>=<VALUE>
+                          Pin the input NAME to VALUE instead of enumerating
+                          it, as scripts can with a line like A = 1
+      --columns <NAMES>   Only write the comma separated columns NAMES, in that
+                          order, instead of every column not starting with _
+      --where <EXPR>      Only write or count the rows EXPR is true for, where
+                          EXPR may use any variable of the script
+      --precedence <PROFILE>
+                          Rank operators by PROFILE: legacy (default, + loosest
+                          to = tightest) or conventional (iff, implies, or,
+                          xor, and), unless the script has a line like
+                          %%! precedence conventional
+      --strict            Reject different operators mixed without
+                          parentheses, as scripts can with %%! strict
+      --lenient           Skip characters scripts can't contain instead of
+                          failing, as older versions did
+      --rule              Draw a vertical rule between input and derived
+                          columns in latex tables
+      --color <WHEN>      Colour pretty tables: auto (default), always or never
+
+Modes:
+      --count             Write the number of rows instead of the table
+      --check <COLUMN>    Check whether COLUMN is a tautology, a contradiction
+                          or satisfiable, exiting with 0, 2 or 1 respectively
+  -e, --equiv <SCRIPT>    Check whether the script is equivalent to SCRIPT,
+                          exiting with 0 if it is and 1 if it isn't
+      --equiv-file <FILE> Check whether the script is equivalent to the
+                          script in FILE
+      --dnf <COLUMN>      Write the canonical sum of products of COLUMN
+      --cnf <COLUMN>      Write the canonical product of sums of COLUMN
+      --minimize <COLUMN> Write the prime implicants and a minimal sum of
+                          products of COLUMN
+      --kmap <COLUMN>     Draw the karnaugh map of COLUMN, which must have
+                          2 to 6 inputs, as text, markdown or latex
+      --groups            Letter the groups of a minimal cover on the map
+      --dont-care <COLUMN>
+                          Treat the rows COLUMN is true for as don't cares
+                          when minimizing
+
+Scripts compared for equivalence may use variables without declaring them,
+and are compared on their last expression over the union of their inputs.
+
+Other:
+  -h, --help              Print this help and exit
+  -V, --version           Print the version and exit
+";
+
+/**
+ * iterator over command line arguments that splits --name=value options
+ */
+struct Args<I: Iterator<Item = String>> {
+    args: I,
+    value: Option<String>
+}
+
+/**
+ * impl block for args
+ */
+impl<I: Iterator<Item = String>> Args<I> {
+
+    /**
+     * gets the next argument, splitting off an inline value
+     */
+    fn next_arg(&mut self) -> Result<Option<String>, String> {
+        if let Some(value) = self.value.take() {
+            return Err(format!("unexpected value `{}`", value));
+        }
+        let arg = match self.args.next() {
+            None => return Ok(None),
+            Some(arg) => arg
+        };
+        if arg.starts_with("--") {
+            if let Some((name, value)) = arg.split_once('=') {
+                self.value = Some(value.to_string());
+                return Ok(Some(name.to_string()));
+            }
+        }
+        return Ok(Some(arg));
+    }
+
+    /**
+     * gets the value of an option
+     */
+    fn value(&mut self, name: &str) -> Result<String, String> {
+        match self.value.take().or_else(|| self.args.next()) {
+            None => Err(format!("option `{}` requires a value", name)),
+            Some(value) => Ok(value)
+        }
+    }
+}
+
+/**
+ * sets the mode, if no other mode has been set
+ */
+fn set_mode(mode: &mut Mode, new_mode: Mode) -> Result<(), String> {
+    if !matches!(mode, Mode::Table) {
+        return Err(String::from("more than one mode given"));
+    }
+    *mode = new_mode;
+    return Ok(());
+}
+
+/**
+ * parses the command line arguments, not including the program name
+ */
+pub fn parse_args<I: IntoIterator<Item = String>>(args: I) -> Result<Command, String> {
+    let mut args = Args {
+        args: args.into_iter(),
+        value: None
+    };
+
+    // options
+    let mut mode = Mode::Table;
+    let mut output: Option<String> = None;
+    let mut format = Format::Text;
+    let mut symbols = Symbols::default();
+    let mut latex = LatexOptions::default();
+    let mut color = Color::Auto;
+    let mut input: Option<In> = None;
+    let mut dont_care: Option<String> = None;
+    let mut groups = false;
+    let mut sets: Vec<String> = Vec::new();
+    let mut filter: Option<String> = None;
+    let mut columns: Option<Vec<String>> = None;
+    let mut precedence = Precedence::default();
+    let mut strict = false;
+    let mut lenient = false;
+    let mut only_files = false;
+
+    while let Some(arg) = args.next_arg()? {
+        let name = if only_files { "" } else { arg.as_str() };
+        match name {
+
+            // help and version options
+            "-h" | "--help" => return Ok(Command::Help),
+            "-V" | "--version" => return Ok(Command::Version),
+
+            // count mode
+            "--count" => set_mode(&mut mode, Mode::Count)?,
+
+            // check mode
+            "--check" => {
+                let column = args.value(&arg)?;
+                set_mode(&mut mode, Mode::Check(column))?;
+            },
+
+            // equivalence mode
+            "-e" | "--equiv" => {
+                let script = args.value(&arg)?;
+                set_mode(&mut mode, Mode::Equiv(In::Cli(script)))?;
+            },
+            "--equiv-file" => {
+                let filename = args.value(&arg)?;
+                set_mode(&mut mode, Mode::Equiv(In::File(filename)))?;
+            },
+
+            // normal form modes
+            "--dnf" => {
+                let column = args.value(&arg)?;
+                set_mode(&mut mode, Mode::Dnf(column))?;
+            },
+            "--cnf" => {
+                let column = args.value(&arg)?;
+                set_mode(&mut mode, Mode::Cnf(column))?;
+            },
+
+            // minimize mode
+            "--minimize" => {
+                let column = args.value(&arg)?;
+                set_mode(&mut mode, Mode::Minimize(column))?;
+            },
+            "--dont-care" => dont_care = Some(args.value(&arg)?),
+
+            // karnaugh map mode
+            "--kmap" => {
+                let column = args.value(&arg)?;
+                set_mode(&mut mode, Mode::Kmap(column))?;
+            },
+            "--groups" => groups = true,
+
+            // cli option
+            "-c" | "--command" => {
+                let script = args.value(&arg)?;
+                if input.is_some() {
+                    return Err(String::from("more than one script given"));
+                }
+                input = Some(In::Cli(script));
+            },
+
+            // output option
+            "-o" | "--output" => {
+                if output.is_some() {
+                    return Err(format!("option `{}` given more than once", arg));
+                }
+                output = Some(args.value(&arg)?);
+            },
+
+            // format option
+            "-f" | "--format" => {
+                let name = args.value(&arg)?;
+                format = match Format::from_name(&name) {
+                    None => return Err(format!("unknown format `{}`", name)),
+                    Some(format) => format
+                };
+            },
+
+            // symbols option
+            "-s" | "--symbols" => {
+                let name = args.value(&arg)?;
+                symbols = match Symbols::from_name(&name) {
+                    None => return Err(format!("unknown symbols `{}`", name)),
+                    Some(symbols) => symbols
+                };
+            },
+
+            // pin option
+            "--set" => sets.push(args.value(&arg)?),
+
+            // columns option
+            "--columns" => {
+                if columns.is_some() {
+                    return Err(format!("option `{}` given more than once", arg));
+                }
+                columns = Some(args.value(&arg)?.split(',').map(|name| name.trim().to_string()).collect());
+            },
+
+            // filter option
+            "--where" => {
+                if filter.is_some() {
+                    return Err(format!("option `{}` given more than once", arg));
+                }
+                filter = Some(args.value(&arg)?);
+            },
+
+            // parsing options
+            "--precedence" => {
+                let name = args.value(&arg)?;
+                precedence = match Precedence::from_name(&name) {
+                    None => return Err(format!("unknown precedence `{}`", name)),
+                    Some(precedence) => precedence
+                };
+            },
+            "--strict" => strict = true,
+            "--lenient" => lenient = true,
+
+            // latex options
+            "--rule" => latex.rule = true,
+
+            // color option
+            "--color" => {
+                let when = args.value(&arg)?;
+                color = match when.as_str() {
+                    "auto" => Color::Auto,
+                    "always" => Color::Always,
+                    "never" => Color::Never,
+                    _ => return Err(format!("unknown color `{}`", when))
+                };
+            },
+
+            // end of options
+            "--" => only_files = true,
+
+            // unknown options
+            name if name.starts_with('-') && name != "-" => {
+                return Err(format!("unknown option `{}`", name));
+            },
+
+            // filename
+            _ => {
+                if input.is_some() {
+                    return Err(String::from("more than one script given"));
+                }
+                input = Some(match arg.as_str() {
+                    "-" => In::Stdin,
+                    _ => In::File(arg)
+                });
+            }
+        }
+    }
+
+    // don't cares only apply to minimizing and maps
+    if dont_care.is_some() && !matches!(mode, Mode::Minimize(_) | Mode::Kmap(_)) {
+        return Err(String::from("option `--dont-care` requires `--minimize` or `--kmap`"));
+    }
+    if filter.is_some() && !matches!(mode, Mode::Table | Mode::Count) {
+        return Err(String::from("option `--where` only applies to tables and `--count`"));
+    }
+    if columns.is_some() && !matches!(mode, Mode::Table) {
+        return Err(String::from("option `--columns` only applies to tables"));
+    }
+    if groups && !matches!(mode, Mode::Kmap(_)) {
+        return Err(String::from("option `--groups` requires `--kmap`"));
+    }
+    if matches!(mode, Mode::Kmap(_)) && !matches!(format, Format::Text | Format::Markdown | Format::Latex) {
+        return Err(String::from("karnaugh maps can only be written as text, markdown or latex"));
+    }
+
+    // read pinned values once the symbols are known
+    let mut pins = Vec::new();
+    for set in sets {
+        let (name, val) = match set.split_once('=') {
+            None => return Err(format!("expected NAME=VALUE in `--set {}`", set)),
+            Some(pin) => pin
+        };
+        let val = match symbols.value(val).or(Symbols::default().value(val)).and_then(Value::to_bool) {
+            None => return Err(format!("invalid value `{}` for `{}`", val, name)),
+            Some(val) => val
+        };
+        pins.push((name.to_string(), val));
+    }
+
+    return Ok(Command::Run(Box::new(Options {
+        mode,
+        input: input.unwrap_or(In::Stdin),
+        dont_care,
+        groups,
+        pins,
+        filter,
+        columns,
+        precedence,
+        strict,
+        lenient,
+        output,
+        format,
+        symbols,
+        latex,
+        color
+    })));
+}
@@ -0,0 +1,72 @@
+
+// uses
+use crate::check::Assignment;
+use crate::error::{Error, ErrorKind, Result};
+use crate::script::Script;
+use crate::value::Value;
+
+/**
+ * the result of comparing two scripts
+ */
+#[derive(Debug, Clone, PartialEq)]
+pub enum Equivalence {
+    Equivalent,
+
+    // the first assignment the scripts differ on, with the value of each
+    Different(Assignment, Value, Value)
+}
+
+/**
+ * checks if two scripts produce the same value for every assignment of the union of their inputs,
+ * where a don't care matches any value
+ */
+pub fn equivalent(left: &Script, right: &Script) -> Result<Equivalence> {
+    let left_result = left.result()?;
+    let right_result = right.result()?;
+
+    // union the inputs by name, keeping the order they are declared in
+    let mut names: Vec<&str> = Vec::new();
+    for script in [left, right] {
+        for i in script.inputs() {
+            let name = script.lines[i].name.as_str();
+            if !names.contains(&name) {
+                names.push(name);
+            }
+        }
+    }
+    if names.len() >= usize::BITS as usize {
+        let span = left.lines.last().map(|line| line.span).unwrap_or_default();
+        return Err(Error::new(ErrorKind::TooManyVariables(names.len()), span, &left.source));
+    }
+
+    // map every input of each script to its place in the union
+    let places = |script: &Script| -> Vec<(usize, usize)> {
+        script.inputs().into_iter().map(|i| {
+            (i, names.iter().position(|name| *name == script.lines[i].name).unwrap())
+        }).collect()
+    };
+    let left_places = places(left);
+    let right_places = places(right);
+
+    // the first input declared is the most significant
+    let mut left_vals = left.pinned();
+    let mut right_vals = right.pinned();
+    for row in 0..(1usize << names.len()) {
+        let bit = |place: usize| (row >> (names.len() - 1 - place)) & 1 == 1;
+        for (i, place) in &left_places {
+            left_vals[*i] = Value::from(bit(*place));
+        }
+        for (i, place) in &right_places {
+            right_vals[*i] = Value::from(bit(*place));
+        }
+        left.assign(&mut left_vals);
+        right.assign(&mut right_vals);
+
+        // stop at the first difference
+        if !left_vals[left_result].agrees(right_vals[right_result]) {
+            let assignment = names.iter().enumerate().map(|(place, name)| (name.to_string(), bit(place))).collect();
+            return Ok(Equivalence::Different(assignment, left_vals[left_result], right_vals[right_result]));
+        }
+    }
+    return Ok(Equivalence::Equivalent);
+}
@@ -0,0 +1,115 @@
+
+// uses
+use std::fmt;
+use crate::script::expr::Span;
+
+/**
+ * the kinds of error a script can contain
+ */
+#[derive(Debug, Clone, PartialEq)]
+pub enum ErrorKind {
+    UnknownToken(String),
+    UnexpectedChar(char),
+    UnbalancedParenthesis,
+    UndeclaredVariable(String),
+    MissingOperand(&'static str),
+    Redeclaration(String),
+    UnexpectedEnd,
+    TooManyVariables(usize),
+    NoExpression,
+    UnknownPragma(String),
+
+    // the names of two operators mixed without parentheses in strict mode
+    MixedOperators(&'static str, &'static str)
+}
+
+/**
+ * displays the error kind as a message
+ */
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ErrorKind::UnknownToken(token) => write!(f, "unexpected token `{}`", token),
+            ErrorKind::UnexpectedChar(c) => write!(f, "unexpected character `{}`", c.escape_debug()),
+            ErrorKind::UnbalancedParenthesis => write!(f, "unbalanced parenthesis"),
+            ErrorKind::UndeclaredVariable(var) => write!(f, "variable `{}` is not declared", var),
+            ErrorKind::MissingOperand(op) => write!(f, "no value for {}", op),
+            ErrorKind::Redeclaration(var) => write!(f, "variable `{}` is already declared", var),
+            ErrorKind::UnexpectedEnd => write!(f, "unexpected end of script"),
+            ErrorKind::TooManyVariables(n) => write!(f, "too many variables to enumerate: {}", n),
+            ErrorKind::NoExpression => write!(f, "script has no expression"),
+            ErrorKind::UnknownPragma(pragma) => write!(f, "unknown pragma `{}`", pragma),
+            ErrorKind::MixedOperators(a, b) => write!(f, "{} and {} need parentheses to be mixed", a, b)
+        }
+    }
+}
+
+/**
+ * an error in a script, with where in the source it happened
+ */
+#[derive(Debug, Clone, PartialEq)]
+pub struct Error {
+    pub kind: ErrorKind,
+    pub span: Span,
+
+    // 1-based line and column of the start of the span
+    pub line: usize,
+    pub col: usize
+}
+
+/**
+ * result type for scripts
+ */
+pub type Result<T> = std::result::Result<T, Error>;
+
+/**
+ * impl block for error
+ */
+impl Error {
+
+    // creates a new error, finding its line and column in the source
+    pub fn new(kind: ErrorKind, span: Span, source: &str) -> Error {
+        let start = span.start.min(source.len());
+        let before = &source[..start];
+        let line = before.matches('\n').count() + 1;
+        let line_start = before.rfind('\n').map(|i| i + 1).unwrap_or(0);
+        let col = source[line_start..start].chars().count() + 1;
+        return Error { kind, span, line, col };
+    }
+
+    /**
+     * renders the error with the offending source line and a caret under the span
+     */
+    pub fn render(&self, name: &str, source: &str) -> String {
+
+        // find the source line
+        let start = self.span.start.min(source.len());
+        let line_start = source[..start].rfind('\n').map(|i| i + 1).unwrap_or(0);
+        let line_end = source[start..].find('\n').map(|i| start + i).unwrap_or(source.len());
+        let text = &source[line_start..line_end];
+
+        // underline the span, stopping at the end of the line
+        let end = self.span.end.clamp(start, line_end);
+        let width = source[start..end].chars().count().max(1);
+        let margin = " ".repeat(self.line.to_string().len());
+        let padding: String = source[line_start..start].chars().map(|c| if c == '\t' { '\t' } else { ' ' }).collect();
+
+        let mut rendered = format!("error: {}\n", self.kind);
+        rendered += &format!("{}--> {}:{}:{}\n", margin, name, self.line, self.col);
+        rendered += &format!("{} |\n", margin);
+        rendered += &format!("{} | {}\n", self.line, text.trim_end());
+        rendered += &format!("{} | {}{}\n", margin, padding, "^".repeat(width));
+        return rendered;
+    }
+}
+
+/**
+ * displays the error with its position
+ */
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} at line {}, column {}", self.kind, self.line, self.col)
+    }
+}
+
+impl std::error::Error for Error {}
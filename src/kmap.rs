@@ -0,0 +1,105 @@
+
+// uses
+use crate::minimize::{minterm, Implicant};
+use crate::table::TruthTable;
+use crate::value::Value;
+
+// fewest and most inputs a map can be drawn for
+pub const MIN_INPUTS: usize = 2;
+pub const MAX_INPUTS: usize = 6;
+
+/**
+ * a karnaugh map of a column
+ */
+#[derive(Debug, Clone, PartialEq)]
+pub struct KMap {
+    pub row_vars: Vec<String>,
+    pub col_vars: Vec<String>,
+
+    // the gray codes of the rows and columns, in order
+    pub row_codes: Vec<u64>,
+    pub col_codes: Vec<u64>,
+    pub cells: Vec<Vec<Value>>,
+
+    // implicants to highlight, lettered in order
+    pub groups: Vec<Implicant>
+}
+
+/**
+ * gets the reflected gray codes of a number of bits
+ */
+pub fn gray_codes(bits: usize) -> Vec<u64> {
+    (0..1u64 << bits).map(|i| i ^ (i >> 1)).collect()
+}
+
+/**
+ * impl block for karnaugh maps
+ */
+impl KMap {
+
+    /**
+     * gets the minterm of a cell
+     */
+    pub fn minterm(&self, row: usize, col: usize) -> u64 {
+        self.row_codes[row] << self.col_vars.len() | self.col_codes[col]
+    }
+
+    /**
+     * gets the letters of the groups covering a cell
+     */
+    pub fn group_letters(&self, row: usize, col: usize) -> String {
+        let minterm = self.minterm(row, col);
+        self.groups.iter().enumerate()
+            .filter(|(_, group)| group.covers(minterm))
+            .map(|(i, _)| group_letter(i))
+            .collect()
+    }
+
+    /**
+     * sets the groups to highlight
+     */
+    pub fn with_groups(mut self, groups: Vec<Implicant>) -> KMap {
+        self.groups = groups;
+        return self;
+    }
+}
+
+/**
+ * gets the letter of a group
+ */
+pub fn group_letter(i: usize) -> char {
+    (b'a' + (i % 26) as u8) as char
+}
+
+/**
+ * lays a column out as a karnaugh map, if it has between 2 and 6 enumerated inputs
+ */
+pub fn kmap(table: &TruthTable, column: usize) -> Option<KMap> {
+    let names: Vec<String> = table.columns.iter().filter(|column| column.is_enumerated()).map(|column| column.name.clone()).collect();
+    if names.len() < MIN_INPUTS || names.len() > MAX_INPUTS {
+        return None;
+    }
+
+    // get the value of every minterm
+    let mut vals = vec![Value::False; 1 << names.len()];
+    for row in &table.rows {
+        vals[minterm(table, row) as usize] = row[column];
+    }
+
+    // the first inputs pick the row and the rest pick the column
+    let (row_vars, col_vars) = names.split_at(names.len() / 2);
+    let row_codes = gray_codes(row_vars.len());
+    let col_codes = gray_codes(col_vars.len());
+    let cells = row_codes.iter().map(|row| {
+        col_codes.iter().map(|col| vals[(row << col_vars.len() | col) as usize]).collect()
+    }).collect();
+
+    return Some(KMap {
+        row_vars: row_vars.to_vec(),
+        col_vars: col_vars.to_vec(),
+        row_codes,
+        col_codes,
+        cells,
+        groups: Vec::new()
+    });
+}
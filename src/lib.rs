@@ -0,0 +1,19 @@
+#![allow(clippy::needless_return)]
+
+// modules
+pub mod check;
+pub mod equiv;
+pub mod kmap;
+pub mod minimize;
+pub mod normal;
+pub mod error;
+pub mod util;
+pub mod script;
+pub mod table;
+pub mod value;
+
+// uses
+pub use error::{Error, ErrorKind};
+pub use script::Script;
+pub use table::{Column, TruthTable};
+pub use value::Value;
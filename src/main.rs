@@ -1,162 +1,268 @@
+#![allow(clippy::needless_return)]
+
 // modules
-mod util;
+mod cli;
 
 // uses
 use std::io::Read;
-use std::collections::HashMap;
-use util::*;
-use util::tokenize::*;
+use std::process::exit;
+use ttable::{Script, TruthTable};
+use ttable::check::{self, Verdict};
+use ttable::equiv::{self, Equivalence};
+use ttable::{kmap, minimize, normal};
+use ttable::script::parse::ParseOptions;
+use ttable::util::Output;
+use cli::{Color, Command, In, Mode};
+
+// exit codes, following sysexits.h
+const EXIT_USAGE: i32 = 64;
+const EXIT_SCRIPT: i32 = 65;
+const EXIT_IO: i32 = 74;
 
 /**
- * main method
+ * Prints a usage error and exits
  */
-fn main() {
+fn print_usage(message: String) -> ! {
+    eprintln!("Error: {}", message);
+    eprintln!("Usage: ttable [OPTIONS] [FILE]");
+    eprintln!("Try 'ttable --help' for more information.");
+    exit(EXIT_USAGE);
+}
 
-    // options
-    let mut out_name: Option<String> = None;
-    let mut in_method: Option<In> = None;
-
-    // iterate over command line arguments to get values
-    let args: Vec<String> = std::env::args().collect();
-    for i in 1..args.len() {
-
-        // match the argument
-        match args[i].as_str() {
-
-            // cli option
-            "-c" => {
-                if in_method.is_some() {
-                    print_usage();
-                    return;
-                }
-                in_method = Some(In::Cli(
-                    if i >= args.len() {
-                        print_usage();
-                        return;
-                    }
-                    else {
-                        args[i].clone()
-                    }
-                ));
-            }
+/**
+ * prints an i/o error and exits
+ */
+fn print_error(e: std::io::Error) -> ! {
+    eprintln!("Error: {}", e);
+    exit(EXIT_IO);
+}
 
-            // output option
-            "-o" => {
-                out_name = Some(
-                    if i+1 >= args.len() {
-                        print_usage();
-                        return;
-                    }
-                    else {
-                        args[i+1].clone()
-                    }
-                );
-            },
+/**
+ * finds a column of the table, exiting if there is none
+ */
+fn find_column(table: &TruthTable, name: &str) -> usize {
+    match table.column(name) {
+        None => print_usage(format!("no column named `{}`", name)),
+        Some(i) => i
+    }
+}
 
-            // filename option
-            filename => {
-                if !in_method.is_some() {
-                    in_method = Some(In::File(filename.to_string()))
-                }
-                
+/**
+ * reads the name and source of a script
+ */
+fn read_input(input: In) -> (String, String) {
+    match input {
+        In::Stdin => {
+            let mut script = String::new();
+            if let Err(e) = std::io::stdin().read_to_string(&mut script) {
+                print_error(e);
+            };
+            (String::from("<stdin>"), script)
+        },
+        In::Cli(script) => (String::from("<command line>"), script),
+        In::File(filename) => {
+            match std::fs::read_to_string(&filename) {
+                Err(e) => {
+                    print_error(e);
+                },
+                Ok(script) => (filename, script)
             }
         }
     }
+}
+
+/**
+ * gets the value of a result, printing the script error and exiting if there is one
+ */
+fn or_exit<T>(result: ttable::error::Result<T>, name: &str, source: &str) -> T {
+    match result {
+        Err(e) => {
+            eprint!("{}", e.render(name, source));
+            exit(EXIT_SCRIPT);
+        },
+        Ok(val) => val
+    }
+}
+
+/**
+ * parses a script, exiting if it has an error
+ */
+fn parse_script(name: &str, source: &str, options: &ParseOptions) -> Script {
+    or_exit(Script::parse_with(source, options), name, source)
+}
+
+/**
+ * evaluates a script, exiting if it has an error
+ */
+fn evaluate(script: &Script, name: &str, source: &str) -> TruthTable {
+    or_exit(script.evaluate(), name, source)
+}
+
+/**
+ * evaluates a script, keeping only the rows the filter is true for
+ */
+fn evaluate_filtered(script: &Script, name: &str, source: &str, filter: Option<&str>, options: &ParseOptions) -> TruthTable {
+    let table = evaluate(script, name, source);
+    match filter {
+        None => table,
+        Some(filter) => {
+            let predicate = or_exit(script.parse_expr(filter, options), "<--where>", filter);
+            table.filter(|row| predicate.eval(row).is_true())
+        }
+    }
+}
+
+/**
+ * pins inputs of the scripts, exiting if none of them has one of the inputs
+ */
+fn pin(scripts: &mut [&mut Script], pins: &[(String, bool)]) {
+    for (input, val) in pins {
+        let mut found = false;
+        for script in scripts.iter_mut() {
+            found |= script.pin(input, *val);
+        }
+        if !found {
+            print_usage(format!("no input named `{}`", input));
+        }
+    }
+}
+
+/**
+ * main method
+ */
+fn main() {
 
-    // if no input options given, default to stdin
-    if in_method.is_none() {
-        in_method = Some(In::Stdin);
+    // parse the command line
+    let options = match cli::parse_args(std::env::args().skip(1)) {
+        Err(message) => print_usage(message),
+        Ok(Command::Help) => {
+            print!("{}", cli::HELP);
+            return;
+        },
+        Ok(Command::Version) => {
+            println!("ttable {}", env!("CARGO_PKG_VERSION"));
+            return;
+        },
+        Ok(Command::Run(options)) => *options
+    };
+
+    // read and parse the script, declaring free variables in expressions to compare
+    let parse_options = ParseOptions {
+        symbols: options.symbols.clone(),
+        declare_free: matches!(options.mode, Mode::Equiv(_)),
+        precedence: options.precedence,
+        strict: options.strict,
+        lenient: options.lenient
+    };
+    let (name, source) = read_input(options.input);
+    let mut script = parse_script(&name, &source, &parse_options);
+
+    // pins for equivalence may name inputs of either script, so they wait for both
+    if !matches!(options.mode, Mode::Equiv(_)) {
+        pin(&mut [&mut script], &options.pins);
     }
 
     // if an output file is given, create an out object
-    let mut output = match out_name {
+    let mut output = match options.output {
         None => Output::stdout(),
         Some(filename) => match Output::file(filename) {
             Err(e) => {
                 print_error(e);
-                return;
             },
             Ok(file) => file
         }
+    }.with_format(options.format).with_symbols(options.symbols).with_latex(options.latex);
+
+    // only colour a terminal unless asked to
+    let color = match options.color {
+        Color::Auto => output.is_terminal(),
+        Color::Always => true,
+        Color::Never => false
     };
+    output = output.with_color(color);
 
-    // get the script from in_method
-    let commented_script = match in_method {
-        None => {
-            let mut script = String::new();
-            match std::io::stdin().read_to_string(&mut script) {
-                Err(e) =>  {
-                    print_error(e);
-                    return;
-                },
-                Ok(_)=>(),
+    // run the mode
+    let result = match options.mode {
+        Mode::Table => {
+            let table = evaluate_filtered(&script, &name, &source, options.filter.as_deref(), &parse_options);
+
+            // write the chosen columns, or the ones that are not hidden
+            let columns = match &options.columns {
+                None => table.visible(),
+                Some(columns) => columns.iter().map(|column| find_column(&table, column)).collect()
             };
-            script
+            output.write_table(&table.select(&columns))
         },
-        Some(in_method) => match in_method {
-            In::Stdin => {
-                let mut script = String::new();
-                match std::io::stdin().read_to_string(&mut script) {
-                    Err(e) =>  {
-                        print_error(e);
-                        return;
-                    },
-                    Ok(_)=>(),
-                };
-                script
-            },
-            In::Cli(script) => script,
-            In::File(filename) => {
-                match std::fs::read_to_string(filename) {
-                    Err(e) => {
-                        print_error(e);
-                        return;
-                    },
-                    Ok(script) => script
-                }
+        Mode::Count => {
+            let table = evaluate_filtered(&script, &name, &source, options.filter.as_deref(), &parse_options);
+            output.writeln(table.rows.len())
+        },
+        Mode::Check(column) => {
+            let table = evaluate(&script, &name, &source);
+            let check = check::check(&table, find_column(&table, &column));
+            if let Err(e) = output.write_check(&check) {
+                print_error(e);
             }
-        }
-    };
-
-    // remove the comment from the script
-    let script = remove_comments(commented_script);
 
-    // print out the top of the truth table
-    let mut line_num: usize = 0;
-    for line in script.split(',') {
-        let mut line = line.to_string();
-        line_num+=1;
+            // the exit status is the answer
+            exit(match check.verdict {
+                Verdict::Tautology => 0,
+                Verdict::Satisfiable => 1,
+                Verdict::Contradiction => 2
+            });
+        },
+        Mode::Dnf(column) => {
+            let table = evaluate(&script, &name, &source);
+            output.writeln(normal::dnf(&table, find_column(&table, &column)))
+        },
+        Mode::Cnf(column) => {
+            let table = evaluate(&script, &name, &source);
+            output.writeln(normal::cnf(&table, find_column(&table, &column)))
+        },
+        Mode::Minimize(column) => {
+            let table = evaluate(&script, &name, &source);
+            let column = find_column(&table, &column);
+            let dont_care = options.dont_care.map(|name| find_column(&table, &name));
+            let minimization = minimize::minimize_column(&table, column, dont_care);
+            output.write_minimization(&minimization, &normal::input_names(&table))
+        },
+        Mode::Kmap(column) => {
+            let table = evaluate(&script, &name, &source);
+            let column = find_column(&table, &column);
+            let inputs = normal::input_names(&table).len();
+            let mut map = match kmap::kmap(&table, column) {
+                None => print_usage(format!("karnaugh maps need {} to {} inputs, not {}", kmap::MIN_INPUTS, kmap::MAX_INPUTS, inputs)),
+                Some(map) => map
+            };
 
-        // print the separator
-        if line_num > 1 {
-            output.write(",\t");
-        }
-        
-        // parse the var
-        let var = match get_token(&mut line) {
-            Some(Token::Var(var)) => var,
-            _ => {
-                println!("Error: Couldn't parse variable in line {}:\n\t\"{}\"",line_num,line);
-                return;
+            // letter the groups of a minimal cover
+            if options.groups {
+                let dont_care = options.dont_care.map(|name| find_column(&table, &name));
+                map = map.with_groups(minimize::minimize_column(&table, column, dont_care).cover);
             }
-        };
-
-        output.write(var);
-    }
-    output.writeln("");
-
-    // call the function to write the rest of the truth table
-    let lines: Vec<&str> = script.split(',').collect();
-    match execute(&mut output, Vec::new(), HashMap::new(), lines.as_slice()) {
-        Err(e) => {
-            print_error(e);
-            return;
+            output.write_kmap(&map)
         },
-        Ok(()) => {
+        Mode::Equiv(other) => {
+            let (other_name, other_source) = read_input(other);
+            let mut other = parse_script(&other_name, &other_source, &parse_options);
+            pin(&mut [&mut script, &mut other], &options.pins);
 
+            // both scripts need a result to compare
+            let left = or_exit(script.result(), &name, &source);
+            let right = or_exit(other.result(), &other_name, &other_source);
+            let equivalence = or_exit(equiv::equivalent(&script, &other), &name, &source);
+            if let Err(e) = output.write_equivalence(&equivalence, &script.lines[left].name, &other.lines[right].name) {
+                print_error(e);
+            }
+
+            // the exit status is the answer
+            exit(match equivalence {
+                Equivalence::Equivalent => 0,
+                Equivalence::Different(..) => 1
+            });
         }
+    };
+    if let Err(e) = result {
+        print_error(e);
     }
-
-
-    output.writeln("Done!");
 }
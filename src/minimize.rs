@@ -0,0 +1,232 @@
+
+// uses
+use std::collections::{BTreeSet, HashSet};
+use crate::normal::Term;
+use crate::table::TruthTable;
+use crate::value::Value;
+
+// most sets of primes petrick's method may keep before giving way to a greedy cover
+const PETRICK_LIMIT: usize = 4096;
+
+/**
+ * a product of inputs, with the first input as the most significant bit
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Implicant {
+    pub bits: u64,
+
+    // the inputs left out of the product
+    pub mask: u64
+}
+
+/**
+ * impl block for implicants
+ */
+impl Implicant {
+
+    /**
+     * checks if the implicant is true for a minterm
+     */
+    pub fn covers(&self, minterm: u64) -> bool {
+        minterm & !self.mask == self.bits
+    }
+
+    /**
+     * counts the literals in the product of the implicant
+     */
+    pub fn literals(&self, inputs: usize) -> u32 {
+        inputs as u32 - self.mask.count_ones()
+    }
+
+    /**
+     * gets the implicant as a term over the inputs
+     */
+    pub fn term(&self, inputs: usize) -> Term {
+        (0..inputs).map(|i| {
+            let bit = 1 << (inputs - 1 - i);
+            if self.mask & bit != 0 { None } else { Some(self.bits & bit != 0) }
+        }).collect()
+    }
+}
+
+/**
+ * the prime implicants of a function and a minimal cover of its minterms
+ */
+#[derive(Debug, Clone, PartialEq)]
+pub struct Minimization {
+    pub primes: Vec<Implicant>,
+    pub cover: Vec<Implicant>,
+
+    // false if the cover came from the greedy fallback and may not be minimal
+    pub exact: bool
+}
+
+/**
+ * finds the prime implicants of the minterms and don't cares
+ */
+fn prime_implicants(inputs: usize, minterms: &[u64], dont_cares: &[u64]) -> Vec<Implicant> {
+    let mut primes = HashSet::new();
+    let mut current: HashSet<Implicant> = minterms.iter().chain(dont_cares)
+        .map(|bits| Implicant { bits: *bits, mask: 0 })
+        .collect();
+
+    // combine implicants that differ in one input until none can be
+    while !current.is_empty() {
+        let mut next = HashSet::new();
+        for implicant in &current {
+            let mut combined = false;
+            for i in 0..inputs {
+                let bit = 1 << i;
+                let other = Implicant { bits: implicant.bits ^ bit, mask: implicant.mask };
+                if implicant.mask & bit == 0 && current.contains(&other) {
+                    next.insert(Implicant { bits: implicant.bits & !bit, mask: implicant.mask | bit });
+                    combined = true;
+                }
+            }
+            if !combined {
+                primes.insert(*implicant);
+            }
+        }
+        current = next;
+    }
+    return primes.into_iter().collect();
+}
+
+/**
+ * removes every set that contains another set, keeping the rest ordered by size and then by their primes
+ */
+fn absorb(sets: Vec<BTreeSet<usize>>) -> Vec<BTreeSet<usize>> {
+    let mut sets: Vec<BTreeSet<usize>> = sets.into_iter().collect::<BTreeSet<_>>().into_iter().collect();
+    sets.sort_by_key(|set| set.len());
+    let mut kept: Vec<BTreeSet<usize>> = Vec::new();
+    for set in sets {
+        if !kept.iter().any(|smaller| smaller.is_subset(&set)) {
+            kept.push(set);
+        }
+    }
+    return kept;
+}
+
+/**
+ * finds the smallest sets of primes covering the minterms, if there aren't too many to search
+ */
+fn petrick(primes: &[Implicant], minterms: &[u64]) -> Option<Vec<BTreeSet<usize>>> {
+
+    // a minterm covered by every prime covering another is covered along with it, so only
+    // the sums of covering primes that contain no other need multiplying, fewest primes first
+    let sums: BTreeSet<BTreeSet<usize>> = minterms.iter()
+        .map(|minterm| (0..primes.len()).filter(|i| primes[*i].covers(*minterm)).collect())
+        .collect();
+    let sums = absorb(sums.into_iter().collect());
+
+    let mut products = vec![BTreeSet::new()];
+    for covering in &sums {
+
+        // give up before multiplying would make too many products
+        let uncovered = products.iter().filter(|product| covering.is_disjoint(product)).count();
+        if products.len() + uncovered * (covering.len() - 1) > PETRICK_LIMIT {
+            return None;
+        }
+
+        // multiply the sum of the covering primes into every product
+        let mut next = Vec::new();
+        for product in &products {
+            if !covering.is_disjoint(product) {
+                next.push(product.clone());
+                continue;
+            }
+            for i in covering {
+                let mut product = product.clone();
+                product.insert(*i);
+                next.push(product);
+            }
+        }
+        products = absorb(next);
+    }
+    return Some(products);
+}
+
+/**
+ * covers the minterms by repeatedly taking the prime that covers the most of the rest
+ */
+fn greedy(primes: &[Implicant], minterms: &[u64], inputs: usize) -> BTreeSet<usize> {
+    let mut cover = BTreeSet::new();
+    let mut uncovered: Vec<u64> = minterms.to_vec();
+    while !uncovered.is_empty() {
+        let best = (0..primes.len()).max_by_key(|i| {
+            let count = uncovered.iter().filter(|minterm| primes[*i].covers(**minterm)).count();
+            (count, std::cmp::Reverse(primes[*i].literals(inputs)))
+        }).unwrap();
+        uncovered.retain(|minterm| !primes[best].covers(*minterm));
+        cover.insert(best);
+    }
+    return cover;
+}
+
+/**
+ * minimizes a function of the inputs with quine-mccluskey and petrick's method
+ */
+pub fn minimize(inputs: usize, minterms: &[u64], dont_cares: &[u64]) -> Minimization {
+
+    // only primes that cover a minterm are of any use
+    let mut primes: Vec<Implicant> = prime_implicants(inputs, minterms, dont_cares).into_iter()
+        .filter(|prime| minterms.iter().any(|minterm| prime.covers(*minterm)))
+        .collect();
+    primes.sort_by_key(|prime| (prime.literals(inputs), std::cmp::Reverse(prime.bits), prime.mask));
+
+    // take the essential primes, the only ones covering some minterm
+    let mut cover = BTreeSet::new();
+    for minterm in minterms {
+        let covering: Vec<usize> = (0..primes.len()).filter(|i| primes[*i].covers(*minterm)).collect();
+        if covering.len() == 1 {
+            cover.insert(covering[0]);
+        }
+    }
+    let rest: Vec<u64> = minterms.iter().copied()
+        .filter(|minterm| !cover.iter().any(|i| primes[*i].covers(*minterm)))
+        .collect();
+
+    // cover the rest with the fewest primes, then the fewest literals, then the earliest primes
+    let literals = |set: &BTreeSet<usize>| set.iter().map(|i| primes[*i].literals(inputs)).sum::<u32>();
+    let (rest_cover, exact) = match petrick(&primes, &rest) {
+        Some(products) => {
+            let best = products.into_iter().min_by(|a, b| (a.len(), literals(a), a).cmp(&(b.len(), literals(b), b)));
+            (best.unwrap_or_default(), true)
+        },
+        None => (greedy(&primes, &rest, inputs), false)
+    };
+    cover.extend(rest_cover);
+
+    return Minimization {
+        cover: cover.into_iter().map(|i| primes[i]).collect(),
+        primes,
+        exact
+    };
+}
+
+/**
+ * gets the number of the minterm for the enumerated inputs of a row
+ */
+pub fn minterm(table: &TruthTable, row: &[Value]) -> u64 {
+    table.columns.iter().zip(row)
+        .filter(|(column, _)| column.is_enumerated())
+        .fold(0, |bits, (_, val)| bits << 1 | val.is_true() as u64)
+}
+
+/**
+ * minimizes a column, treating the rows it doesn't care about or another column is true for as don't cares
+ */
+pub fn minimize_column(table: &TruthTable, column: usize, dont_care: Option<usize>) -> Minimization {
+    let inputs = table.columns.iter().filter(|column| column.is_enumerated()).count();
+    let mut minterms = Vec::new();
+    let mut dont_cares = Vec::new();
+    for row in &table.rows {
+        if row[column].is_dont_care() || dont_care.is_some_and(|i| row[i].is_true()) {
+            dont_cares.push(minterm(table, row));
+        }
+        else if row[column].is_true() {
+            minterms.push(minterm(table, row));
+        }
+    }
+    return minimize(inputs, &minterms, &dont_cares);
+}
@@ -0,0 +1,115 @@
+
+// uses
+use crate::table::TruthTable;
+use crate::util::tokenize::*;
+use crate::value::Value;
+
+/**
+ * a product or sum of inputs, with the value each input is tested for or none if it is left out
+ */
+pub type Term = Vec<Option<bool>>;
+
+/**
+ * gets the names of the enumerated input columns of a table
+ */
+pub fn input_names(table: &TruthTable) -> Vec<String> {
+    table.columns.iter().filter(|column| column.is_enumerated()).map(|column| column.name.clone()).collect()
+}
+
+/**
+ * gets the values of the enumerated input columns in a row as a term
+ */
+fn row_term(table: &TruthTable, row: &[Value]) -> Term {
+    table.columns.iter().zip(row).filter(|(column, _)| column.is_enumerated()).map(|(_, val)| val.to_bool()).collect()
+}
+
+/**
+ * gets the rows a column is true for
+ */
+pub fn minterms(table: &TruthTable, column: usize) -> Vec<Term> {
+    table.rows.iter().filter(|row| row[column].is_true()).map(|row| row_term(table, row)).collect()
+}
+
+/**
+ * gets the rows a column is false for
+ */
+pub fn maxterms(table: &TruthTable, column: usize) -> Vec<Term> {
+    table.rows.iter().filter(|row| row[column].is_false()).map(|row| row_term(table, row)).collect()
+}
+
+/**
+ * gets the rows a column doesn't care about
+ */
+pub fn dont_cares(table: &TruthTable, column: usize) -> Vec<Term> {
+    table.rows.iter().filter(|row| row[column].is_dont_care()).map(|row| row_term(table, row)).collect()
+}
+
+/**
+ * writes a variable, negated if it is tested for false
+ */
+fn literal(name: &str, val: bool) -> String {
+    if val { name.to_string() } else { format!("{}{}", name, POST_VAL_NEGATION) }
+}
+
+/**
+ * writes a term as a product that is true for it
+ */
+pub fn product(names: &[String], term: &[Option<bool>]) -> String {
+    let literals: Vec<String> = names.iter().zip(term)
+        .filter_map(|(name, val)| val.map(|val| literal(name, val)))
+        .collect();
+    if literals.is_empty() {
+        return String::from("1");
+    }
+    return literals.join(CONJUNCTION);
+}
+
+/**
+ * writes a term as a sum that is false for it
+ */
+pub fn sum(names: &[String], term: &[Option<bool>]) -> String {
+    let literals: Vec<String> = names.iter().zip(term)
+        .filter_map(|(name, val)| val.map(|val| literal(name, !val)))
+        .collect();
+    match literals.len() {
+        0 => String::from("0"),
+        1 => literals[0].clone(),
+        _ => format!("{}{}{}", OPEN, literals.join(DISJUNCTION), CLOSE)
+    }
+}
+
+/**
+ * writes the sum of the products of terms
+ */
+pub fn sum_of_products(names: &[String], terms: &[Term]) -> String {
+    if terms.is_empty() {
+        return String::from("0");
+    }
+    let products: Vec<String> = terms.iter().map(|term| product(names, term)).collect();
+    return products.join(&format!(" {} ", DISJUNCTION));
+}
+
+/**
+ * writes the product of the sums of terms
+ */
+pub fn product_of_sums(names: &[String], terms: &[Term]) -> String {
+    if terms.is_empty() {
+        return String::from("1");
+    }
+    let sums: Vec<String> = terms.iter().map(|term| sum(names, term)).collect();
+    return sums.join(&format!(" {} ", CONJUNCTION));
+}
+
+/**
+ * gets the canonical disjunctive normal form of a column, leaving out its don't cares
+ */
+pub fn dnf(table: &TruthTable, column: usize) -> String {
+    sum_of_products(&input_names(table), &minterms(table, column))
+}
+
+/**
+ * gets the canonical conjunctive normal form of a column, leaving out its don't cares
+ */
+pub fn cnf(table: &TruthTable, column: usize) -> String {
+    product_of_sums(&input_names(table), &maxterms(table, column))
+}
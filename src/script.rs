@@ -0,0 +1,159 @@
+
+// modules
+pub mod expr;
+pub mod parse;
+
+// uses
+use crate::table::{Column, TruthTable};
+use crate::error::{Error, ErrorKind, Result};
+use crate::value::Value;
+use expr::{Expr, Span};
+use parse::ParseOptions;
+
+// variables whose names start with this are evaluated but hidden from output
+pub const HIDDEN_PREFIX: char = '_';
+
+/**
+ * a line of a script, either declaring or assigning a variable
+ */
+#[derive(Debug, Clone, PartialEq)]
+pub struct Line {
+    pub name: String,
+
+    // none if the line declares an input
+    pub expr: Option<Expr>,
+
+    // the source of the expression, empty for declarations
+    pub text: String,
+
+    // the value a declared input is pinned to instead of being enumerated
+    pub pin: Option<bool>,
+
+    // whether the variable is left out of output, though it is still evaluated
+    pub hidden: bool,
+    pub span: Span
+}
+
+/**
+ * a script parsed into an expression tree for every line
+ */
+#[derive(Debug, Clone)]
+pub struct Script {
+    pub source: String,
+    pub lines: Vec<Line>
+}
+
+/**
+ * impl block for script
+ */
+impl Script {
+
+    /**
+     * parses a script from its source
+     */
+    pub fn parse(source: &str) -> Result<Script> {
+        parse::parse(source, &ParseOptions::default())
+    }
+
+    /**
+     * parses a script from its source with the given options
+     */
+    pub fn parse_with(source: &str, options: &ParseOptions) -> Result<Script> {
+        parse::parse(source, options)
+    }
+
+    /**
+     * parses an expression over the variables of the script, whose values are at the same index
+     * in the rows of its table
+     */
+    pub fn parse_expr(&self, source: &str, options: &ParseOptions) -> Result<Expr> {
+        parse::parse_expr(source, self, options)
+    }
+
+    /**
+     * gets the columns the script produces
+     */
+    pub fn columns(&self) -> Vec<Column> {
+        self.lines.iter().map(|line| Column {
+            name: line.name.clone(),
+            expr: line.expr.as_ref().map(|_| line.text.clone()),
+            pin: line.pin,
+            hidden: line.hidden
+        }).collect()
+    }
+
+    /**
+     * gets the indices of the lines that declare inputs to enumerate, leaving out pinned ones
+     */
+    pub fn inputs(&self) -> Vec<usize> {
+        (0..self.lines.len()).filter(|i| self.lines[*i].expr.is_none() && self.lines[*i].pin.is_none()).collect()
+    }
+
+    /**
+     * pins a declared input to a value, returning false if there is no input with the name
+     */
+    pub fn pin(&mut self, name: &str, val: bool) -> bool {
+        match self.lines.iter_mut().find(|line| line.name == name && line.expr.is_none()) {
+            None => false,
+            Some(line) => {
+                line.pin = Some(val);
+                true
+            }
+        }
+    }
+
+    /**
+     * gets a value for every line, with the pinned inputs set
+     */
+    pub fn pinned(&self) -> Vec<Value> {
+        self.lines.iter().map(|line| Value::from(line.pin.unwrap_or(false))).collect()
+    }
+
+    /**
+     * gets the line whose value the script produces, which is its last derived line
+     */
+    pub fn result(&self) -> Result<usize> {
+        match self.lines.iter().rposition(|line| line.expr.is_some()) {
+            None => {
+                let span = self.lines.last().map(|line| line.span).unwrap_or_default();
+                Err(Error::new(ErrorKind::NoExpression, span, &self.source))
+            },
+            Some(i) => Ok(i)
+        }
+    }
+
+    /**
+     * walks the tree of every assignment in order, given the values of the inputs
+     */
+    pub fn assign(&self, vals: &mut [Value]) {
+        for (i, line) in self.lines.iter().enumerate() {
+            if let Some(expr) = &line.expr {
+                vals[i] = expr.eval(vals);
+            }
+        }
+    }
+
+    /**
+     * evaluates the script over every assignment of its declared variables that are not pinned
+     */
+    pub fn evaluate(&self) -> Result<TruthTable> {
+        let inputs = self.inputs();
+        if inputs.len() >= usize::BITS as usize {
+            let span = self.lines[inputs[usize::BITS as usize - 1]].span;
+            return Err(Error::new(ErrorKind::TooManyVariables(inputs.len()), span, &self.source));
+        }
+
+        // the first input declared is the most significant
+        let mut table = TruthTable::new(self.columns());
+        let mut vals = self.pinned();
+        for row in 0..(1usize << inputs.len()) {
+            for (j, i) in inputs.iter().enumerate() {
+                vals[*i] = Value::from((row >> (inputs.len() - 1 - j)) & 1 == 1);
+            }
+
+            self.assign(&mut vals);
+            table.rows.push(vals.clone());
+        }
+        return Ok(table);
+    }
+}
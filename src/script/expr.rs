@@ -0,0 +1,185 @@
+
+// uses
+use crate::util::tokenize::*;
+use crate::value::Value;
+
+/**
+ * a byte range in the source of a script
+ */
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Span {
+    pub start: usize,
+    pub end: usize
+}
+
+/**
+ * impl block for span
+ */
+impl Span {
+
+    // creates a new span
+    pub fn new(start: usize, end: usize) -> Span {
+        return Span { start, end };
+    }
+
+    /**
+     * creates the smallest span covering both spans
+     */
+    pub fn to(self, other: Span) -> Span {
+        return Span::new(self.start.min(other.start), self.end.max(other.end));
+    }
+}
+
+/**
+ * binary operators, including their negated forms
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BinOp {
+    Disjunction,
+    NegDisjunction,
+    Conjunction,
+    NegConjunction,
+    ExDisjunction,
+    NegExDisjunction,
+    Implication,
+    NegImplication,
+    Equivalence,
+    NegEquivalence
+}
+
+/**
+ * impl block for binary operators
+ */
+impl BinOp {
+
+    /**
+     * gets the operator for a token, if it is a binary operator
+     */
+    pub fn from_symbol(op: &str) -> Option<BinOp> {
+        Some(match op {
+            DISJUNCTION => BinOp::Disjunction,
+            NEG_DISJUNCTION => BinOp::NegDisjunction,
+            CONJUNCTION => BinOp::Conjunction,
+            NEG_CONJUNCTION => BinOp::NegConjunction,
+            EX_DISJUNCTION => BinOp::ExDisjunction,
+            NEG_EX_DISJUNCTION => BinOp::NegExDisjunction,
+            IMPLICATION => BinOp::Implication,
+            NEG_IMPLICATION => BinOp::NegImplication,
+            EQUIVALENCE => BinOp::Equivalence,
+            NEG_EQUIVALENCE => BinOp::NegEquivalence,
+            _ => return None
+        })
+    }
+
+    /**
+     * gets the script symbol of the operator
+     */
+    pub fn symbol(self) -> &'static str {
+        match self {
+            BinOp::Disjunction => DISJUNCTION,
+            BinOp::NegDisjunction => NEG_DISJUNCTION,
+            BinOp::Conjunction => CONJUNCTION,
+            BinOp::NegConjunction => NEG_CONJUNCTION,
+            BinOp::ExDisjunction => EX_DISJUNCTION,
+            BinOp::NegExDisjunction => NEG_EX_DISJUNCTION,
+            BinOp::Implication => IMPLICATION,
+            BinOp::NegImplication => NEG_IMPLICATION,
+            BinOp::Equivalence => EQUIVALENCE,
+            BinOp::NegEquivalence => NEG_EQUIVALENCE
+        }
+    }
+
+    /**
+     * gets the operator without its negation, which is the family strict mode keeps apart
+     */
+    pub fn family(self) -> BinOp {
+        match self {
+            BinOp::Disjunction | BinOp::NegDisjunction => BinOp::Disjunction,
+            BinOp::Conjunction | BinOp::NegConjunction => BinOp::Conjunction,
+            BinOp::ExDisjunction | BinOp::NegExDisjunction => BinOp::ExDisjunction,
+            BinOp::Implication | BinOp::NegImplication => BinOp::Implication,
+            BinOp::Equivalence | BinOp::NegEquivalence => BinOp::Equivalence
+        }
+    }
+
+    /**
+     * gets the name of the operator
+     */
+    pub fn name(self) -> &'static str {
+        match self {
+            BinOp::Disjunction => "disjunction",
+            BinOp::NegDisjunction => "negated disjunction",
+            BinOp::Conjunction => "conjunction",
+            BinOp::NegConjunction => "negated conjunction",
+            BinOp::ExDisjunction => "exclusive disjunction",
+            BinOp::NegExDisjunction => "negated exclusive disjunction",
+            BinOp::Implication => "implication",
+            BinOp::NegImplication => "negated implication",
+            BinOp::Equivalence => "equivalence",
+            BinOp::NegEquivalence => "negated equivalence"
+        }
+    }
+
+    /**
+     * applies the operator to two values
+     */
+    pub fn apply(self, a: Value, b: Value) -> Value {
+        match self {
+            BinOp::Disjunction => a | b,
+            BinOp::NegDisjunction => !(a | b),
+            BinOp::Conjunction => a & b,
+            BinOp::NegConjunction => !(a & b),
+            BinOp::ExDisjunction => a ^ b,
+            BinOp::NegExDisjunction => !(a ^ b),
+            BinOp::Implication => !a | b,
+            BinOp::NegImplication => a & !b,
+            BinOp::Equivalence => !(a ^ b),
+            BinOp::NegEquivalence => a ^ b
+        }
+    }
+}
+
+/**
+ * the kinds of node in an expression tree
+ */
+#[derive(Debug, Clone, PartialEq)]
+pub enum ExprKind {
+
+    // the index of the line that defines the variable
+    Var(usize),
+    Const(Value),
+    Not(Box<Expr>),
+    Binary(BinOp, Box<Expr>, Box<Expr>)
+}
+
+/**
+ * an expression tree with the span of source it was parsed from
+ */
+#[derive(Debug, Clone, PartialEq)]
+pub struct Expr {
+    pub kind: ExprKind,
+    pub span: Span
+}
+
+/**
+ * impl block for expressions
+ */
+impl Expr {
+
+    // creates a new expression
+    pub fn new(kind: ExprKind, span: Span) -> Expr {
+        return Expr { kind, span };
+    }
+
+    /**
+     * evaluates the expression, looking variables up by line index in vals
+     */
+    pub fn eval(&self, vals: &[Value]) -> Value {
+        match &self.kind {
+            ExprKind::Var(i) => vals[*i],
+            ExprKind::Const(val) => *val,
+            ExprKind::Not(expr) => !expr.eval(vals),
+            ExprKind::Binary(op, a, b) => op.apply(a.eval(vals), b.eval(vals))
+        }
+    }
+}
@@ -0,0 +1,544 @@
+
+// uses
+use crate::util::symbols::Symbols;
+use crate::util::tokenize::*;
+use super::expr::*;
+use super::{Line, Script, HIDDEN_PREFIX};
+use crate::error::{Error, ErrorKind, Result};
+use crate::value::Value;
+
+/**
+ * options for parsing a script
+ */
+#[derive(Debug, Clone, Default)]
+pub struct ParseOptions {
+
+    // symbols read as values alongside 1 and 0
+    pub symbols: Symbols,
+
+    // declare variables the first time they are used instead of failing,
+    // so the don't care symbol is never read in place of one
+    pub declare_free: bool,
+
+    // how binary operators rank, unless the script has a pragma for it
+    pub precedence: Precedence,
+
+    // reject different binary operators mixed without parentheses
+    pub strict: bool,
+
+    // skip chars that start no token instead of failing, as scripts once could
+    pub lenient: bool
+}
+
+// comments at the start of a line that begin with this set options for the script, which
+// older scripts could not have meant as plain comments, unlike ones starting `%!`
+const PRAGMA: &str = "%%!";
+
+/**
+ * applies the pragmas of a script, like `%%! precedence conventional` or `%%! strict`, to its options,
+ * which are the comments starting a line before the end of the script
+ */
+fn pragmas(source: &str, options: &ParseOptions) -> Result<ParseOptions> {
+    let mut applied = options.clone();
+    for token in Lexer::new(source, &options.symbols) {
+        let (comment, span) = match token {
+            Ok(Spanned { node: Token::EndScript, .. }) => break,
+            Ok(Spanned { node: Token::Comment(comment), span }) => (comment, span),
+            _ => continue
+        };
+        let line_start = source[..span.start].rfind('\n').map(|i| i + 1).unwrap_or(0);
+        let pragma = match comment.strip_prefix(PRAGMA) {
+            Some(pragma) if source[line_start..span.start].chars().all(is_whitespace) => pragma,
+            _ => continue
+        };
+        let span = Span::new(span.start, span.start + comment.trim_end().len());
+        let unknown = || Error::new(ErrorKind::UnknownPragma(pragma.trim().to_string()), span, source);
+        match pragma.split_whitespace().collect::<Vec<&str>>()[..] {
+            ["precedence", name] => applied.precedence = Precedence::from_name(name).ok_or_else(unknown)?,
+            ["strict"] => applied.strict = true,
+            _ => return Err(unknown())
+        }
+    }
+    return Ok(applied);
+}
+
+/**
+ * splits a script into tokens up to its end, leaving out comments and skipping chars that start none if lenient
+ */
+fn lex<'a>(source: &'a str, options: &'a ParseOptions) -> Result<Vec<Spanned<Token<'a>>>> {
+    let mut tokens = Vec::new();
+    for token in Lexer::new(source, &options.symbols) {
+        match token {
+            Err(_) if options.lenient => continue,
+            Err(e) => return Err(Error::new(ErrorKind::UnexpectedChar(e.c), e.span, source)),
+            Ok(Spanned { node: Token::Comment(_), .. }) => continue,
+            Ok(token) => {
+                let end = token.node == Token::EndScript;
+                tokens.push(token);
+                if end {
+                    break;
+                }
+            }
+        }
+    }
+    return Ok(tokens);
+}
+
+/**
+ * recursive descent parser over the tokens of a script, without its comments
+ */
+struct Parser<'a> {
+    source: &'a str,
+    declare_free: bool,
+    precedence: Precedence,
+    strict: bool,
+
+    // the symbols for values, read as values wherever they are not declared variables
+    symbols: &'a Symbols,
+    tokens: Vec<Spanned<Token<'a>>>,
+    pos: usize,
+    lines: Vec<Line>
+}
+
+/**
+ * impl block for parser
+ */
+impl<'a> Parser<'a> {
+
+    /**
+     * gets the token at an offset from the current one
+     */
+    fn peek_at(&self, offset: usize) -> Option<&Token<'a>> {
+        self.tokens.get(self.pos + offset).map(|token| &token.node)
+    }
+
+    /**
+     * gets the current token
+     */
+    fn peek(&self) -> Option<&Token<'a>> {
+        self.peek_at(0)
+    }
+
+    /**
+     * gets the span of the current token, or an empty span at the end of the script
+     */
+    fn span(&self) -> Span {
+        match self.tokens.get(self.pos) {
+            Some(token) => token.span,
+            None => Span::new(self.source.len(), self.source.len())
+        }
+    }
+
+    /**
+     * consumes the current token
+     */
+    fn bump(&mut self) -> Span {
+        let span = self.span();
+        self.pos += 1;
+        return span;
+    }
+
+    /**
+     * checks if the current token ends a line
+     */
+    fn at_line_end(&self) -> bool {
+        matches!(self.peek(), None | Some(Token::EndLine) | Some(Token::EndScript))
+    }
+
+    /**
+     * creates an error at a span of the script
+     */
+    fn error(&self, kind: ErrorKind, span: Span) -> Error {
+        Error::new(kind, span, self.source)
+    }
+
+    /**
+     * creates an error for the current token
+     */
+    fn unexpected(&self) -> Error {
+        match self.peek() {
+            Some(Token::Op(CLOSE)) => self.error(ErrorKind::UnbalancedParenthesis, self.span()),
+            Some(_) => {
+
+                // name the token as it was written, since operators have other spellings
+                let span = self.span();
+                self.error(ErrorKind::UnknownToken(self.source[span.start..span.end].to_string()), span)
+            },
+            None => self.error(ErrorKind::UnexpectedEnd, self.span())
+        }
+    }
+
+    /**
+     * finds the line that defines a variable
+     */
+    fn lookup(&self, var: &str) -> Option<usize> {
+        self.lines.iter().position(|line| line.name == var)
+    }
+
+    /**
+     * parses every line of the script
+     */
+    fn parse_script(&mut self) -> Result<()> {
+        loop {
+            self.parse_line()?;
+            match self.peek() {
+                Some(Token::EndLine) => {
+                    self.bump();
+                },
+                Some(Token::EndScript) | None => return Ok(()),
+                Some(_) => return Err(self.unexpected())
+            }
+        }
+    }
+
+    /**
+     * gets the value a variable that is not declared stands for, if it is one of the symbols
+     *
+     * the don't care symbol is read as a variable when declaring free variables, since it
+     * defaults to X, while the true and false symbols only ever are when chosen
+     */
+    fn symbol(&self, var: &str) -> Option<Value> {
+        match self.symbols.value(var)? {
+            _ if self.lookup(var).is_some() => None,
+            Value::DontCare if self.declare_free => None,
+            val => Some(val)
+        }
+    }
+
+    /**
+     * gets the value a line pins a new variable to, if it is a variable equal to a value on its own
+     *
+     * a lone expression to compare and the last line of a script, which no expression could use
+     * the pin in, are read as the expression they also spell
+     */
+    fn peek_pin(&self, var: &str) -> Option<bool> {
+        if self.declare_free || self.peek_at(1) != Some(&Token::Op(EQUIVALENCE)) || self.lookup(var).is_some() {
+            return None;
+        }
+        let val = match self.peek_at(2)? {
+            Token::Val(val) => *val,
+            Token::Var(symbol) => self.symbol(symbol)?,
+            _ => return None
+        };
+        match self.peek_at(3) {
+            Some(Token::EndLine) if !matches!(self.peek_at(4), None | Some(Token::EndScript)) => val.to_bool(),
+            _ => None
+        }
+    }
+
+    /**
+     * parses a declaration, a pinned declaration, an assignment or a bare expression
+     */
+    fn parse_line(&mut self) -> Result<()> {
+        let start = self.span();
+
+        // a variable on its own is a declaration
+        if let Some(Token::Var(var)) = self.peek() {
+            let var = var.to_string();
+            let hidden = var.starts_with(HIDDEN_PREFIX);
+            let last = matches!(self.peek_at(1), None | Some(Token::EndScript));
+
+            // though a variable alone on the last line of an expression to compare is that expression
+            let declaration = (last && !self.declare_free) || self.peek_at(1) == Some(&Token::EndLine);
+            let assignment = self.peek_at(1) == Some(&Token::Op(ASSIGNMENT));
+
+            // a new variable equal to a value is declared pinned to it
+            if let Some(pin) = self.peek_pin(&var) {
+                self.pos += 2;
+                let span = start.to(self.bump());
+                self.lines.push(Line {
+                    name: var,
+                    expr: None,
+                    text: String::new(),
+                    pin: Some(pin),
+                    hidden,
+                    span
+                });
+                return Ok(());
+            }
+            if declaration || assignment {
+                if self.lookup(&var).is_some() {
+                    return Err(self.error(ErrorKind::Redeclaration(var), start));
+                }
+                self.bump();
+            }
+
+            // declare the variable
+            if declaration {
+                self.lines.push(Line {
+                    name: var,
+                    expr: None,
+                    text: String::new(),
+                    pin: None,
+                    hidden,
+                    span: start
+                });
+                return Ok(());
+            }
+
+            // assign the variable
+            if assignment {
+                self.bump();
+                let expr = self.parse_expr(0)?;
+
+                // the expression may have declared it as a free variable
+                if self.lookup(&var).is_some() {
+                    return Err(self.error(ErrorKind::Redeclaration(var), start));
+                }
+                let text = self.text(expr.span);
+                let span = start.to(expr.span);
+                self.lines.push(Line {
+                    name: var,
+                    expr: Some(expr),
+                    text,
+                    pin: None,
+                    hidden,
+                    span
+                });
+                return Ok(());
+            }
+        }
+
+        // a bare expression is named after its source
+        let expr = self.parse_expr(0)?;
+        let text = self.text(expr.span);
+        self.lines.push(Line {
+            name: text.clone(),
+            span: expr.span,
+            expr: Some(expr),
+            text,
+            pin: None,
+            hidden: false
+        });
+        return Ok(());
+    }
+
+    /**
+     * gets the source of the tokens in a span, with the whitespace and comments between them collapsed to a space
+     */
+    fn text(&self, span: Span) -> String {
+        let mut text = String::new();
+        let mut end = span.start;
+        let first = self.tokens.partition_point(|token| token.span.start < span.start);
+        let last = self.tokens.partition_point(|token| token.span.end <= span.end);
+        for token in &self.tokens[first..last] {
+            if token.span.start > end {
+                text.push(' ');
+            }
+            text += &self.source[token.span.start..token.span.end];
+            end = token.span.end;
+        }
+        return text;
+    }
+
+    /**
+     * gets the binary operator at the current token and how many tokens it spans
+     */
+    fn peek_binop(&self) -> Option<(BinOp, usize)> {
+        let flipped = |op: &str| BinOp::from_symbol(flip(op)?);
+        match self.peek()? {
+
+            // an operator negated by a prefix
+            Token::Op(PRE_NEGATION) => match self.peek_at(1)? {
+                Token::Op(op) => Some((flipped(op)?, 2)),
+                _ => None
+            },
+
+            // an operator, negated by a postfix after its symbol as scripts have always been able to,
+            // while after any other spelling, like `&&!`, the postfix negates the operand as a prefix
+            Token::Op(op) => {
+                let span = self.span();
+                if self.peek_at(1) == Some(&Token::Op(POST_OP_NEGATION)) && &self.source[span.start..span.end] == *op {
+                    return Some((flipped(op)?, 2));
+                }
+                return Some((BinOp::from_symbol(op)?, 1));
+            },
+            _ => None
+        }
+    }
+
+    /**
+     * parses binary operators that bind at least as tightly as min_prec
+     */
+    fn parse_expr(&mut self, min_prec: i32) -> Result<Expr> {
+        self.parse_binary(min_prec).map(|(expr, _)| expr)
+    }
+
+    /**
+     * checks that strict mode allows an operator next to an operand's unparenthesized operator
+     */
+    fn check_mix(&self, op: BinOp, operand: Option<BinOp>, span: Span) -> Result<()> {
+        match operand {
+            Some(other) if self.strict && other.family() != op.family() => {
+                Err(self.error(ErrorKind::MixedOperators(other.family().name(), op.family().name()), span))
+            },
+            _ => Ok(())
+        }
+    }
+
+    /**
+     * parses binary operators that bind at least as tightly as min_prec, along with the
+     * operator at the top of the expression if it is not parenthesized
+     */
+    fn parse_binary(&mut self, min_prec: i32) -> Result<(Expr, Option<BinOp>)> {
+        let mut left = self.parse_unary()?;
+        let mut left_op = None;
+        while let Some((op, len)) = self.peek_binop() {
+
+            // stop at looser operators
+            let op_prec = self.precedence.prec(op.symbol()).unwrap_or(0);
+            if op_prec < min_prec {
+                break;
+            }
+            let op_span = self.span().to(self.tokens[self.pos + len - 1].span);
+            self.check_mix(op, left_op, op_span)?;
+            self.pos += len;
+
+            // the right operand binds everything tighter, and as tight too if the operator groups from the right
+            if self.at_line_end() || self.peek() == Some(&Token::Op(CLOSE)) {
+                return Err(self.error(ErrorKind::MissingOperand(op.name()), op_span));
+            }
+            let next_prec = match self.precedence.assoc(op.symbol()) {
+                Some(Assoc::Right) => op_prec,
+                _ => op_prec + 1
+            };
+            let (right, right_op) = self.parse_binary(next_prec)?;
+            self.check_mix(op, right_op, op_span)?;
+            let span = left.span.to(right.span);
+            left = Expr::new(ExprKind::Binary(op, Box::new(left), Box::new(right)), span);
+            left_op = Some(op);
+        }
+        return Ok((left, left_op));
+    }
+
+    /**
+     * parses a prefix negation, which may also be written with the postfix operator negation
+     */
+    fn parse_unary(&mut self) -> Result<Expr> {
+        if matches!(self.peek(), Some(Token::Op(PRE_NEGATION)) | Some(Token::Op(POST_OP_NEGATION))) {
+            let start = self.bump();
+            if self.at_line_end() || self.peek() == Some(&Token::Op(CLOSE)) {
+                return Err(self.error(ErrorKind::MissingOperand("negation"), start));
+            }
+            let expr = self.parse_unary()?;
+            let span = start.to(expr.span);
+            return Ok(Expr::new(ExprKind::Not(Box::new(expr)), span));
+        }
+        return self.parse_postfix();
+    }
+
+    /**
+     * parses a value followed by postfix negations
+     */
+    fn parse_postfix(&mut self) -> Result<Expr> {
+        let mut expr = self.parse_primary()?;
+        while self.peek() == Some(&Token::Op(POST_VAL_NEGATION)) {
+            let span = expr.span.to(self.bump());
+            expr = Expr::new(ExprKind::Not(Box::new(expr)), span);
+        }
+        return Ok(expr);
+    }
+
+    /**
+     * parses a value, a variable or a parenthesized expression
+     */
+    fn parse_primary(&mut self) -> Result<Expr> {
+        let span = self.span();
+        match self.peek().cloned() {
+
+            // if a value
+            Some(Token::Val(val)) => {
+                self.bump();
+                return Ok(Expr::new(ExprKind::Const(val), span));
+            },
+
+            // if a variable
+            Some(Token::Var(var)) => {
+                if let Some(val) = self.symbol(var) {
+                    self.bump();
+                    return Ok(Expr::new(ExprKind::Const(val), span));
+                }
+                let i = match self.lookup(var) {
+
+                    // declare a free variable before the line using it
+                    None if self.declare_free => {
+                        let hidden = var.starts_with(HIDDEN_PREFIX);
+                        self.lines.push(Line {
+                            name: var.to_string(),
+                            expr: None,
+                            text: String::new(),
+                            pin: None,
+                            hidden,
+                            span
+                        });
+                        self.lines.len() - 1
+                    },
+                    None => {
+                        return Err(self.error(ErrorKind::UndeclaredVariable(var.to_string()), span));
+                    },
+                    Some(i) => i
+                };
+                self.bump();
+                return Ok(Expr::new(ExprKind::Var(i), span));
+            },
+
+            // if a parenthesized expression
+            Some(Token::Op(OPEN)) => {
+                self.bump();
+                let mut expr = self.parse_expr(0)?;
+                if self.peek() != Some(&Token::Op(CLOSE)) {
+                    return Err(self.error(ErrorKind::UnbalancedParenthesis, span));
+                }
+                expr.span = span.to(self.bump());
+                return Ok(expr);
+            },
+
+            // if anything else
+            _ => return Err(self.unexpected())
+        }
+    }
+}
+
+/**
+ * parses an expression over the variables of a script, such as a predicate on its rows
+ */
+pub fn parse_expr(source: &str, script: &Script, options: &ParseOptions) -> Result<Expr> {
+    let options = &pragmas(&script.source, options)?;
+    let mut parser = Parser {
+        tokens: lex(source, options)?,
+        source,
+        declare_free: false,
+        precedence: options.precedence,
+        strict: options.strict,
+        symbols: &options.symbols,
+        pos: 0,
+        lines: script.lines.clone()
+    };
+    let expr = parser.parse_expr(0)?;
+    if parser.peek().is_some() {
+        return Err(parser.unexpected());
+    }
+    return Ok(expr);
+}
+
+/**
+ * parses a script into an expression tree for every line
+ */
+pub fn parse(source: &str, options: &ParseOptions) -> Result<Script> {
+    let options = &pragmas(source, options)?;
+    let mut parser = Parser {
+        tokens: lex(source, options)?,
+        source,
+        declare_free: options.declare_free,
+        precedence: options.precedence,
+        strict: options.strict,
+        symbols: &options.symbols,
+        pos: 0,
+        lines: Vec::new()
+    };
+    parser.parse_script()?;
+    return Ok(Script {
+        source: source.to_string(),
+        lines: parser.lines
+    });
+}
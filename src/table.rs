@@ -0,0 +1,95 @@
+
+// uses
+use crate::value::Value;
+
+/**
+ * a column of a truth table
+ */
+#[derive(Debug, Clone, PartialEq)]
+pub struct Column {
+    pub name: String,
+    pub expr: Option<String>,
+
+    // the value an input is pinned to instead of being enumerated
+    pub pin: Option<bool>,
+
+    // whether the column is left out of output unless asked for
+    pub hidden: bool
+}
+
+/**
+ * impl block for column
+ */
+impl Column {
+
+    /**
+     * checks if the column is an enumerated input rather than a derived expression
+     */
+    pub fn is_input(&self) -> bool {
+        self.expr.is_none()
+    }
+
+    /**
+     * checks if the column is an input that takes every value rather than a pinned one
+     */
+    pub fn is_enumerated(&self) -> bool {
+        self.is_input() && self.pin.is_none()
+    }
+}
+
+/**
+ * a fully evaluated truth table
+ */
+#[derive(Debug, Clone, PartialEq)]
+pub struct TruthTable {
+    pub columns: Vec<Column>,
+    pub rows: Vec<Vec<Value>>
+}
+
+/**
+ * impl block for truth table
+ */
+impl TruthTable {
+
+    // creates an empty table with the given columns
+    pub fn new(columns: Vec<Column>) -> TruthTable {
+        return TruthTable {
+            columns,
+            rows: Vec::new()
+        };
+    }
+
+    /**
+     * gets the index of the column with the given name
+     */
+    pub fn column(&self, name: &str) -> Option<usize> {
+        self.columns.iter().position(|column| column.name == name)
+    }
+
+    /**
+     * gets the indices of the columns that are not hidden
+     */
+    pub fn visible(&self) -> Vec<usize> {
+        (0..self.columns.len()).filter(|i| !self.columns[*i].hidden).collect()
+    }
+
+    /**
+     * gets a table with only the given columns, in the given order
+     */
+    pub fn select(&self, columns: &[usize]) -> TruthTable {
+        return TruthTable {
+            columns: columns.iter().map(|i| self.columns[*i].clone()).collect(),
+            rows: self.rows.iter().map(|row| columns.iter().map(|i| row[*i]).collect()).collect()
+        };
+    }
+
+    /**
+     * gets a table with only the rows that keep returns true for
+     */
+    pub fn filter<F: Fn(&[Value]) -> bool>(&self, keep: F) -> TruthTable {
+        return TruthTable {
+            columns: self.columns.clone(),
+            rows: self.rows.iter().filter(|row| keep(row)).cloned().collect()
+        };
+    }
+}
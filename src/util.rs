@@ -1,108 +1,44 @@
 // modules
+pub mod format;
+pub mod symbols;
 pub mod tokenize;
 
 // uses
 use std::fs::File;
-use std::io::{self,Write};
+use std::io::{self,IsTerminal,Write};
 use std::fmt::Display;
-use std::collections::HashMap;
-use tokenize::*;
+use crate::table::TruthTable;
+use crate::check::{Assignment, Check, Verdict};
+use crate::equiv::Equivalence;
+use crate::kmap::KMap;
+use crate::minimize::Minimization;
+use crate::normal::sum_of_products;
+use crate::value::Value;
+use format::Format;
+use format::latex::LatexOptions;
+use symbols::Symbols;
 
 /**
- * Prints usage notes for the applications
+ * where output is written to
  */
-pub fn print_usage() {
-    println!("Usage: ttable <file script | pass directly option>")
-}
-
-/**
- * prints an error
- */
-pub fn print_error(e: std::io::Error) {
-    println!("Error: {}", e);
-}
-
-/**
- * helper enum for remove_comments method
- */
-enum CommentState {
-    None,
-    Uniline,
-    Multiline
-}
-
-/** 
- * removes comments from a script
- */
-pub fn remove_comments(old_script: String) -> String {
-
-    // init the new script and comment state
-    let mut new_script = String::new();
-    let mut comment_state = CommentState::None;
-
-    //iterate over evey char in the old scrip
-    for c in old_script.chars() {
-        match comment_state {
-
-            // if there isn't actively a comment
-            CommentState::None => {
-                match c {
-
-                    // if the beginning of a uniline comment
-                    '%' => comment_state = CommentState::Uniline,
-
-                    // if the beginning of a multiline comment
-                    '[' => comment_state = CommentState::Multiline,
-                    
-                    // if whitespace
-                    ' ' => (),
-                    '\t' =>(),
-                    '\n' => (),
-
-                    // if not the beginning of a comment
-                    c => new_script.push(c)
-                }
-            }
-
-            // if there is a uniline comment
-            CommentState::Uniline => {
-                
-                // exit the comment state
-                if c == '\n' {
-                    comment_state = CommentState::None;
-                }
-            }
-
-            //if there is a multiline comment
-            CommentState::Multiline => {
-
-                //exit the comment state
-                if c == ']' {
-                    comment_state = CommentState::None;
-                }
-            }
-        }
-    }
-    return new_script;
-}
-
-/**
- * enum for the input method
- */
-pub enum In {
-    Cli(String),
-    Stdin,
-    File(String)
+pub enum Sink {
+    File {
+        file:File
+    },
+    Stdout
 }
 
 /**
  * struct used to handle output
  */
-pub enum Output {
-    File {
-        file:File
-    },
-    Stdout
+pub struct Output {
+    sink: Sink,
+    pub format: Format,
+    pub symbols: Symbols,
+    pub latex: LatexOptions,
+
+    // colour true and false cells in pretty tables
+    pub color: bool
 }
 
 /**
@@ -114,563 +50,196 @@ impl Output {
     pub fn file(filename:String)->io::Result<Output> {
 
         // create the file
-        let file = match File::create(filename) {
-            Err(e) => return Err(e),
-            Ok(file) => file
-        };
-        return Ok(Output::File{file});
+        let file = File::create(filename)?;
+        return Ok(Output {
+            sink: Sink::File{file},
+            format: Format::Text,
+            symbols: Symbols::default(),
+            latex: LatexOptions::default(),
+            color: false
+        });
     }
     
     // creates a new output object for stdout
     pub fn stdout()->Output {
-        return Output::Stdout;
+        return Output {
+            sink: Sink::Stdout,
+            format: Format::Text,
+            symbols: Symbols::default(),
+            latex: LatexOptions::default(),
+            color: false
+        };
     }
 
     /**
-     * writes output to self
+     * sets the format tables are written in
      */
-    pub fn write<S:Display>(&mut self, output: S) {
-        match self {
-
-            // if self is a file writer
-            Output::File{file} => {
-                file.write_fmt(format_args!("{}",output)).unwrap();
-            }
-
-            // if self is stdin
-            Output::Stdout => {
-                print!("{}",output);
-            }
-        }
+    pub fn with_format(mut self, format: Format) -> Output {
+        self.format = format;
+        return self;
     }
 
     /**
-     * writes output to self with a newline at the end
+     * sets the symbols written for true and false
      */
-    pub fn writeln<S:Display>(&mut self, output: S) {
-        match self {
-
-            // if self is a file writer
-            Output::File{file} => {
-                file.write_all(format!("{}",output).as_ref()).unwrap();
-                file.write_all(&['\n' as u8]).unwrap();
-            }
-
-            // if self is stdin
-            Output::Stdout => {
-                println!("{}",output);
-            }
-        }
+    pub fn with_symbols(mut self, symbols: Symbols) -> Output {
+        self.symbols = symbols;
+        return self;
     }
 
     /**
-     * writes the true value
+     * sets the options for latex tables
      */
-    pub fn write_true(&mut self) {
-        self.write(1);
+    pub fn with_latex(mut self, latex: LatexOptions) -> Output {
+        self.latex = latex;
+        return self;
     }
 
     /**
-     * writes the false value
+     * sets whether pretty tables are coloured
      */
-    pub fn write_false(&mut self) {
-        self.write(0);
+    pub fn with_color(mut self, color: bool) -> Output {
+        self.color = color;
+        return self;
     }
-}
-
-fn operate(operator:&str,op_stack:&mut Vec<&str>,val_stack:&mut Vec<bool>,var_stack:&mut Vec<String>,var_map:&mut HashMap<String,bool>) -> io::Result<()> {
-    match operator {
-
-        // if assignment
-        ASSIGNMENT => {
-
-            var_map.insert(match var_stack.pop() {
-                None => {
-                    return Err(io::Error::new(io::ErrorKind::Other, "no variable for asssignment"));
-                },
-                Some(var) => var
-            }, match val_stack.last() {
-                None => {
-                    return Err(io::Error::new(io::ErrorKind::Other, "no value for asssignment"));
-                },
-                Some(val) => *val
-            });
-        },
-
-        // if disjunction
-        DISJUNCTION => {
-
-            // get values
-            let b = match val_stack.pop() {
-                None => {
-                    return Err(io::Error::new(io::ErrorKind::Other, "no value for disjunction"));
-                },
-                Some(val) => val
-            };
-
-            // get values
-            let a = match val_stack.pop() {
-                None => {
-                    return Err(io::Error::new(io::ErrorKind::Other, "no value for disjunction"));
-                },
-                Some(val) => val
-            };
-
-            // do calculations
-            let result = a || b;
-            val_stack.push(result);
-        }
-
-        // if neg disjunction
-        NEG_DISJUNCTION => {
-
-            // get values
-            let b = match val_stack.pop() {
-                None => {
-                    return Err(io::Error::new(io::ErrorKind::Other, "no value for disjunction"));
-                },
-                Some(val) => val
-            };
-
-            // get values
-            let a = match val_stack.pop() {
-                None => {
-                    return Err(io::Error::new(io::ErrorKind::Other, "no value for disjunction"));
-                },
-                Some(val) => val
-            };
-
-            // do calculations
-            let result = a || b;
-            val_stack.push(!result);
-        }
-
-        // if conjunction
-        CONJUNCTION => {
-
-            // get values
-            let b = match val_stack.pop() {
-                None => {
-                    return Err(io::Error::new(io::ErrorKind::Other, "no value for disjunction"));
-                },
-                Some(val) => val
-            };
-
-            // get values
-            let a = match val_stack.pop() {
-                None => {
-                    return Err(io::Error::new(io::ErrorKind::Other, "no value for disjunction"));
-                },
-                Some(val) => val
-            };
-
-            // do calculations
-            let result = a && b;
-            val_stack.push(result);
-        }
-
-        // if neg conjunction
-        NEG_CONJUNCTION => {
-
-            // get values
-            let b = match val_stack.pop() {
-                None => {
-                    return Err(io::Error::new(io::ErrorKind::Other, "no value for disjunction"));
-                },
-                Some(val) => val
-            };
-
-            // get values
-            let a = match val_stack.pop() {
-                None => {
-                    return Err(io::Error::new(io::ErrorKind::Other, "no value for disjunction"));
-                },
-                Some(val) => val
-            };
-
-            // do calculations
-            let result = a && b;
-            val_stack.push(!result);
-        }
-
-        // if exclusive disjunction
-        EX_DISJUNCTION => {
-
-            // get values
-            let b = match val_stack.pop() {
-                None => {
-                    return Err(io::Error::new(io::ErrorKind::Other, "no value for disjunction"));
-                },
-                Some(val) => val
-            };
-
-            // get values
-            let a = match val_stack.pop() {
-                None => {
-                    return Err(io::Error::new(io::ErrorKind::Other, "no value for disjunction"));
-                },
-                Some(val) => val
-            };
-
-            // do calculations
-            let result = (!a && b) || (a && !b);
-            val_stack.push(result);
-        }
-
-        // if neg exclusive disjunction
-        NEG_EX_DISJUNCTION => {
-
-            // get values
-            let b = match val_stack.pop() {
-                None => {
-                    return Err(io::Error::new(io::ErrorKind::Other, "no value for disjunction"));
-                },
-                Some(val) => val
-            };
-
-            // get values
-            let a = match val_stack.pop() {
-                None => {
-                    return Err(io::Error::new(io::ErrorKind::Other, "no value for disjunction"));
-                },
-                Some(val) => val
-            };
-
-            // do calculations
-            let result = (!a && b) || (a && !b);
-            val_stack.push(!result);
-        }
-
-        // if implication
-        IMPLICATION => {
-
-            // get values
-            let b = match val_stack.pop() {
-                None => {
-                    return Err(io::Error::new(io::ErrorKind::Other, "no value for disjunction"));
-                },
-                Some(val) => val
-            };
-
-            // get values
-            let a = match val_stack.pop() {
-                None => {
-                    return Err(io::Error::new(io::ErrorKind::Other, "no value for disjunction"));
-                },
-                Some(val) => val
-            };
-
-            // do calculations
-            let result = !a || b;
-            val_stack.push(result);
-        }
 
-        // if neg implication
-        NEG_IMPLICATION => {
-
-            // get values
-            let b = match val_stack.pop() {
-                None => {
-                    return Err(io::Error::new(io::ErrorKind::Other, "no value for disjunction"));
-                },
-                Some(val) => val
-            };
-
-            // get values
-            let a = match val_stack.pop() {
-                None => {
-                    return Err(io::Error::new(io::ErrorKind::Other, "no value for disjunction"));
-                },
-                Some(val) => val
-            };
-
-            // do calculations
-            let result = !a || b;
-            val_stack.push(!result);
+    /**
+     * checks if the output is written to a terminal
+     */
+    pub fn is_terminal(&self) -> bool {
+        match self.sink {
+            Sink::File{..} => false,
+            Sink::Stdout => io::stdout().is_terminal()
         }
+    }
 
-        // if equivalence
-        EQUIVALENCE => {
-
-            // get values
-            let b = match val_stack.pop() {
-                None => {
-                    return Err(io::Error::new(io::ErrorKind::Other, "no value for disjunction"));
-                },
-                Some(val) => val
-            };
+    /**
+     * writes output to self
+     */
+    pub fn write<S:Display>(&mut self, output: S) -> io::Result<()> {
+        match &mut self.sink {
 
-            // get values
-            let a = match val_stack.pop() {
-                None => {
-                    return Err(io::Error::new(io::ErrorKind::Other, "no value for disjunction"));
-                },
-                Some(val) => val
-            };
+            // if self is a file writer
+            Sink::File{file} => file.write_fmt(format_args!("{}",output)),
 
-            // do calculations
-            let result = a==b;
-            val_stack.push(result);
+            // if self is stdout
+            Sink::Stdout => io::stdout().write_fmt(format_args!("{}",output))
         }
+    }
 
-        // if neg equivalence
-        NEG_EQUIVALENCE => {
-
-            // get values
-            let b = match val_stack.pop() {
-                None => {
-                    return Err(io::Error::new(io::ErrorKind::Other, "no value for disjunction"));
-                },
-                Some(val) => val
-            };
+    /**
+     * writes output to self with a newline at the end
+     */
+    pub fn writeln<S:Display>(&mut self, output: S) -> io::Result<()> {
+        self.write(output)?;
+        self.write('\n')
+    }
 
-            // get values
-            let a = match val_stack.pop() {
-                None => {
-                    return Err(io::Error::new(io::ErrorKind::Other, "no value for disjunction"));
-                },
-                Some(val) => val
-            };
+    /**
+     * writes the true value
+     */
+    pub fn write_true(&mut self) -> io::Result<()> {
+        let symbol = self.symbols.t.clone();
+        self.write(symbol)
+    }
 
-            // do calculations
-            let result = a==b;
-            val_stack.push(!result);
-        }
+    /**
+     * writes the false value
+     */
+    pub fn write_false(&mut self) -> io::Result<()> {
+        let symbol = self.symbols.f.clone();
+        self.write(symbol)
+    }
 
-        // if prenegation
-        PRE_NEGATION => (),
+    /**
+     * writes the don't care value
+     */
+    pub fn write_dont_care(&mut self) -> io::Result<()> {
+        let symbol = self.symbols.x.clone();
+        self.write(symbol)
+    }
 
-        // if post var negation
-        POST_VAL_NEGATION => {
-            let val = match val_stack.pop() {
-                None => {
-                    return Err(io::Error::new(io::ErrorKind::Other, "no value for negation"));
-                },
-                Some(val) => val
-            };
+    /**
+     * writes an assignment of values to variables
+     */
+    pub fn write_assignment(&mut self, assignment: &Assignment) -> io::Result<()> {
+        let vals: Vec<String> = assignment.iter().map(|(var, val)| format!("{}={}", var, self.symbols.of(Value::from(*val)))).collect();
+        self.writeln(vals.join(", "))
+    }
 
-            val_stack.push(!val);
+    /**
+     * writes the result of checking a column
+     */
+    pub fn write_check(&mut self, check: &Check) -> io::Result<()> {
+        match check.verdict {
+            Verdict::Tautology => self.writeln(format_args!("{} is a tautology", check.column))?,
+            Verdict::Contradiction => self.writeln(format_args!("{} is a contradiction", check.column))?,
+            Verdict::Satisfiable => self.writeln(format_args!("{} is satisfiable", check.column))?
         }
-
-        // if post op negation
-        POST_OP_NEGATION => {
-            let op = match op_stack.pop() {
-                None => {
-                    return Err(io::Error::new(io::ErrorKind::Other, "no operator for negation"));
-                },
-                Some(val) => val
-            };
-
-            op_stack.push(match flip(op) {
-                Err(e) => {
-                    return Err(e);
-                },
-                Ok(op) => op
-            });
+        if let (Verdict::Satisfiable, Some(witness)) = (check.verdict, &check.witness) {
+            self.write("witness: ")?;
+            self.write_assignment(witness)?;
         }
-
-        // if else
-        _ => ()
-    };
-
-    return Ok(());
-}
-
-/**
- * Executes the script
- */
-pub fn execute(output: &mut Output,  mut vals: Vec<bool>, mut var_map: HashMap<String,bool>, lines: &[&str]) -> io::Result<()> {
-
-    // breaks if the slice is empty
-    if lines.len() < 1 {
-
-        // print every value in vals
-        let mut i = 0;
-        while i < vals.len() {
-            match vals[i] {
-                true => output.write_true(),
-                false => output.write_false()
-            }
-
-            // add comma and tab
-            if i != vals.len()-1 {
-                output.write(",\t")
-            }
-
-            // increment
-            i+=1;
+        if let Some(counterexample) = &check.counterexample {
+            self.write("counterexample: ")?;
+            self.write_assignment(counterexample)?;
         }
-
-        // write endl
-        output.writeln("");
-
         return Ok(());
     }
 
-    // solve expression
-    match evaluate(lines[0].to_string(), &mut var_map)? {
-        
-        // if variable assigned
-        Some(val) =>  {
-            // append result to vals
-            vals.push(val);
-    
-            // recurse
-            execute(output, vals.clone(), var_map.clone(),&lines[1..lines.len()])?;
-        },
-
-        // if variable declared
-        None => {
-
-            // append false to new vals
-            vals.push(false);
-            var_map.insert(lines[0].to_string(),false);
-
-            // recurse once
-            execute(output, vals.clone(), var_map.clone(),&lines[1..lines.len()])?;
-
-            // change last in vals to true
-            let i = vals.len()-1;
-            vals[i] = true;
-            var_map.insert(lines[0].to_string(),true);
-
-            // recurse once
-            execute(output, vals.clone(), var_map.clone(),&lines[1..lines.len()])?;
-        }
-    };
-    
-    return Ok(());
-}
-
-
-fn evaluate(mut expression:String,var_map:&mut HashMap<String,bool>)->io::Result<Option<bool>> {
-
-    // create stacks
-    let mut val_stack: Vec<bool> = Vec::new();
-    let mut op_stack: Vec<&str> = Vec::new();
-    let mut var_stack: Vec<String> = Vec::new();
-
-    // loop over expr
-    let mut token = get_token(&mut expression);
-    while token.is_some() {
-
-        // perform an action depending on the token
-        match token {
-
-            // if none
-            None => (),
-
-            // if endline
-            Some(Token::EndLine) => (),
-
-            // if endscript
-            Some(Token::EndScript) => (),
-
-            // if the token is a value
-            Some(Token::Val(val)) => {
-                if !op_stack.is_empty() && *op_stack.last().unwrap() == PRE_NEGATION {
-                    val_stack.push(!val);
-                    op_stack.pop();
-                }
-                else {
-                    val_stack.push(val);
-                }
-            },
-
-            // if token is a variable
-            Some(Token::Var(var)) => {
-                match var_map.get(&var) {
-
-                    // if the variable has been assigned.
-                    Some(val) => {
-
-                        // if the previous operator is is pre_neg, flip the operator
-                        if !op_stack.is_empty() && *op_stack.last().unwrap() == PRE_NEGATION {
-                            val_stack.push(!*val);
-                            op_stack.pop();
-                        }
-
-                        // push the value to the stack
-                        else {
-                            val_stack.push(*val);
-                        }
-                    },
-
-                    // if the variable has not been assigned
-                    None => {
-                        
-                        // if there is room for an operator
-                        if op_stack.is_empty() && var_stack.is_empty() {
-                            var_stack.push(var);
-                        }
-
-                        // if there isn't room for an operator.
-                        else {
-                            return Err(io::Error::new(io::ErrorKind::Other, format!("variable {} not assigned", var)))
-                        }
-                    }
-
-                }
-            },
-
-            // if the token is an operator
-            Some(Token::Op(mut op)) => {
-
-                // if the previous operator is is pre_neg, flip the operator
-                if !op_stack.is_empty() && *op_stack.last().unwrap() == PRE_NEGATION {
-                    match flip(op) {
-                        Err(_)=>(),
-                        Ok(operator)=>{
-                            op = operator;
-                            op_stack.pop();
-                        }
-                    }
-                }
-
-                // if the previous operators precidence is lower
-                while !op_stack.is_empty() && *op_stack.last().unwrap() != OPEN && prec(op_stack.last().unwrap()).unwrap() > prec(op).unwrap() {
-
-                    // get operator
-                    let operator = op_stack.pop().unwrap();
-
-                    // choose operation
-                    operate(operator,&mut op_stack,&mut val_stack,&mut var_stack,var_map)?;
-                }
-
-                // handle if op is close
-                if op == CLOSE {
-                    match op_stack.pop() {
-                        None=> {
-                            return Err(io::Error::new(io::ErrorKind::Other, "unexpected close parenthesis"));
-                        }
-                        Some(OPEN)=>(),
-                        Some(_)=>{
-                            return Err(io::Error::new(io::ErrorKind::Other, "unexpected close parenthesis"));
-                        }
-                    }
-                }
-
-                // add the operator to the operator stack
-                else {
-                    op_stack.push(op);
-                }
+    /**
+     * writes the result of comparing two scripts, given the name of each one's result
+     */
+    pub fn write_equivalence(&mut self, equivalence: &Equivalence, left: &str, right: &str) -> io::Result<()> {
+        match equivalence {
+            Equivalence::Equivalent => self.writeln("equivalent"),
+            Equivalence::Different(assignment, left_val, right_val) => {
+                self.writeln("not equivalent")?;
+                self.write("assignment: ")?;
+                self.write_assignment(assignment)?;
+                let left_symbol = self.symbols.of(*left_val).to_string();
+                let right_symbol = self.symbols.of(*right_val).to_string();
+                self.writeln(format_args!("{}: {}", left, left_symbol))?;
+                self.writeln(format_args!("{}: {}", right, right_symbol))
             }
         }
+    }
 
-        // get the next token
-        token = get_token(&mut expression);
+    /**
+     * writes the prime implicants and minimal cover of a function of the named inputs
+     */
+    pub fn write_minimization(&mut self, minimization: &Minimization, names: &[String]) -> io::Result<()> {
+        let term = |implicant: &crate::minimize::Implicant| implicant.term(names.len());
+        self.writeln("prime implicants:")?;
+        for prime in &minimization.primes {
+            self.writeln(format_args!("  {}", sum_of_products(names, &[term(prime)])))?;
+        }
+        let cover: Vec<_> = minimization.cover.iter().map(term).collect();
+        match minimization.exact {
+            true => self.writeln("minimal cover:")?,
+            false => self.writeln("cover (greedy, may not be minimal):")?
+        }
+        return self.writeln(format_args!("  {}", sum_of_products(names, &cover)));
     }
 
-    // perform the rest of the operations
-    while !op_stack.is_empty() {
-        let operator = op_stack.pop().unwrap();
-        operate(operator,&mut op_stack,&mut val_stack,&mut var_stack,var_map)?;
+    /**
+     * writes a karnaugh map in the output's format
+     */
+    pub fn write_kmap(&mut self, kmap: &KMap) -> io::Result<()> {
+        format::kmap::write_kmap(self, kmap)
     }
 
-    // return the result
-    if val_stack.is_empty() {
-        return Ok(None);
+    /**
+     * writes a whole truth table in the output's format
+     */
+    pub fn write_table(&mut self, table: &TruthTable) -> io::Result<()> {
+        match self.format {
+            Format::Text => format::text::write_table(self, table),
+            Format::Csv => format::csv::write_table(self, table),
+            Format::Markdown => format::markdown::write_table(self, table),
+            Format::Latex => format::latex::write_table(self, table),
+            Format::Json => format::json::write_table(self, table),
+            Format::Pretty => format::pretty::write_table(self, table)
+        }
     }
-    return Ok(Some(val_stack.pop().unwrap()))
-    
-}
\ No newline at end of file
+}
@@ -0,0 +1,43 @@
+
+// modules
+pub mod csv;
+pub mod json;
+pub mod kmap;
+pub mod latex;
+pub mod markdown;
+pub mod pretty;
+pub mod text;
+
+/**
+ * formats a table can be written in
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Text,
+    Csv,
+    Markdown,
+    Latex,
+    Json,
+    Pretty
+}
+
+/**
+ * impl block for format
+ */
+impl Format {
+
+    /**
+     * gets a format from its name on the command line
+     */
+    pub fn from_name(name: &str) -> Option<Format> {
+        match name {
+            "text" => Some(Format::Text),
+            "csv" => Some(Format::Csv),
+            "markdown" | "md" => Some(Format::Markdown),
+            "latex" => Some(Format::Latex),
+            "json" => Some(Format::Json),
+            "pretty" => Some(Format::Pretty),
+            _ => None
+        }
+    }
+}
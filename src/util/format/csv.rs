@@ -0,0 +1,46 @@
+
+// uses
+use std::io;
+use crate::table::TruthTable;
+use crate::util::Output;
+
+// records end in a carriage return and line feed
+const RECORD_END: &str = "\r\n";
+
+/**
+ * writes a field, quoting it if it contains a separator, quote or line break
+ */
+fn write_field(output: &mut Output, field: &str) -> io::Result<()> {
+    if field.contains([',', '"', '\r', '\n']) {
+        return output.write(format_args!("\"{}\"", field.replace('"', "\"\"")));
+    }
+    return output.write(field);
+}
+
+/**
+ * writes a table as RFC 4180 comma separated values, header first
+ */
+pub fn write_table(output: &mut Output, table: &TruthTable) -> io::Result<()> {
+
+    // write the header
+    for (i, column) in table.columns.iter().enumerate() {
+        if i > 0 {
+            output.write(',')?;
+        }
+        write_field(output, &column.name)?;
+    }
+    output.write(RECORD_END)?;
+
+    // write every row
+    for row in &table.rows {
+        for (i, val) in row.iter().enumerate() {
+            if i > 0 {
+                output.write(',')?;
+            }
+            let symbol = output.symbols.of(*val).to_string();
+            write_field(output, &symbol)?;
+        }
+        output.write(RECORD_END)?;
+    }
+    return Ok(());
+}
@@ -0,0 +1,65 @@
+
+// uses
+use std::io;
+use crate::table::TruthTable;
+use crate::util::Output;
+use crate::value::Value;
+
+/**
+ * quotes and escapes a json string
+ */
+fn quote(text: &str) -> String {
+    let mut quoted = String::from("\"");
+    for c in text.chars() {
+        match c {
+            '"' => quoted += "\\\"",
+            '\\' => quoted += "\\\\",
+            '\n' => quoted += "\\n",
+            '\r' => quoted += "\\r",
+            '\t' => quoted += "\\t",
+            c if (c as u32) < 0x20 => quoted += &format!("\\u{:04x}", c as u32),
+            c => quoted.push(c)
+        }
+    }
+    quoted.push('"');
+    return quoted;
+}
+
+/**
+ * writes a table as a json document with its column metadata and rows
+ */
+pub fn write_table(output: &mut Output, table: &TruthTable) -> io::Result<()> {
+    output.writeln("{")?;
+
+    // write the columns
+    output.writeln("  \"columns\": [")?;
+    for (i, column) in table.columns.iter().enumerate() {
+        let (kind, expr) = match &column.expr {
+            None => ("input", String::from("null")),
+            Some(expr) => ("derived", quote(expr))
+        };
+        let separator = if i + 1 < table.columns.len() { "," } else { "" };
+        let pin = match column.pin {
+            None => "null",
+            Some(true) => "true",
+            Some(false) => "false"
+        };
+        output.writeln(format_args!("    {{\"name\": {}, \"kind\": \"{}\", \"expr\": {}, \"pin\": {}}}{}", quote(&column.name), kind, expr, pin, separator))?;
+    }
+    output.writeln("  ],")?;
+
+    // write the rows
+    output.writeln("  \"rows\": [")?;
+    for (i, row) in table.rows.iter().enumerate() {
+        let vals: Vec<&str> = row.iter().map(|val| match val {
+            Value::True => "true",
+            Value::False => "false",
+            Value::DontCare => "null"
+        }).collect();
+        let separator = if i + 1 < table.rows.len() { "," } else { "" };
+        output.writeln(format_args!("    [{}]{}", vals.join(", "), separator))?;
+    }
+    output.writeln("  ]")?;
+
+    return output.writeln("}");
+}
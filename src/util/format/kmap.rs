@@ -0,0 +1,104 @@
+
+// uses
+use std::io;
+use crate::kmap::{group_letter, KMap};
+use crate::normal::sum_of_products;
+use crate::util::Output;
+use super::{latex, markdown, Format};
+
+/**
+ * writes a gray code as binary digits
+ */
+fn code(code: u64, bits: usize) -> String {
+    format!("{:0width$b}", code, width = bits)
+}
+
+/**
+ * joins variable names, leaving out the separator if they are all one char
+ */
+fn join(vars: &[String]) -> String {
+    if vars.iter().all(|var| var.chars().count() == 1) {
+        return vars.concat();
+    }
+    return vars.join(",");
+}
+
+/**
+ * gets the text of every cell of the map, header row and column included
+ */
+fn grid(output: &Output, kmap: &KMap) -> Vec<Vec<String>> {
+    let mut grid = Vec::new();
+
+    // the header row
+    let mut header = vec![format!("{}\\{}", join(&kmap.row_vars), join(&kmap.col_vars))];
+    header.extend(kmap.col_codes.iter().map(|col| code(*col, kmap.col_vars.len())));
+    grid.push(header);
+
+    // a row for every row code
+    for (r, row) in kmap.cells.iter().enumerate() {
+        let mut cells = vec![code(kmap.row_codes[r], kmap.row_vars.len())];
+        for (c, val) in row.iter().enumerate() {
+            let letters = kmap.group_letters(r, c);
+            let symbol = output.symbols.of(*val);
+            cells.push(if letters.is_empty() { symbol.to_string() } else { format!("{} {}", symbol, letters) });
+        }
+        grid.push(cells);
+    }
+    return grid;
+}
+
+/**
+ * writes a karnaugh map in the output's format, followed by its groups
+ */
+pub fn write_kmap(output: &mut Output, kmap: &KMap) -> io::Result<()> {
+    let grid = grid(output, kmap);
+    match output.format {
+
+        // a markdown table with the codes in bold
+        Format::Markdown => {
+            for (r, row) in grid.iter().enumerate() {
+                let cells: Vec<String> = row.iter().enumerate().map(|(c, cell)| {
+                    if (r == 0) != (c == 0) { format!("**{}**", markdown::escape(cell)) } else { markdown::escape(cell) }
+                }).collect();
+                output.writeln(format_args!("| {} |", cells.join(" | ")))?;
+                if r == 0 {
+                    output.writeln(format_args!("|{}", ":-:|".repeat(row.len())))?;
+                }
+            }
+        },
+
+        // a latex tabular with rules after the codes
+        Format::Latex => {
+            output.writeln(format_args!("\\begin{{tabular}}{{c|{}}}", "c".repeat(kmap.col_codes.len())))?;
+            for (r, row) in grid.iter().enumerate() {
+                let cells: Vec<String> = row.iter().map(|cell| latex::escape(cell)).collect();
+                output.writeln(format_args!("{} \\\\", cells.join(" & ")))?;
+                if r == 0 {
+                    output.writeln("\\hline")?;
+                }
+            }
+            output.writeln("\\end{tabular}")?;
+        },
+
+        // aligned text for the terminal
+        _ => {
+            let width = grid.iter().skip(1).flatten().chain(&grid[0][1..]).map(|cell| cell.chars().count()).max().unwrap_or(1);
+            let corner = grid.iter().map(|row| row[0].chars().count()).max().unwrap_or(1);
+            for row in &grid {
+                output.write(format_args!("{:>corner$}", row[0], corner = corner))?;
+                for cell in &row[1..] {
+                    output.write(format_args!("  {:>width$}", cell, width = width))?;
+                }
+                output.writeln("")?;
+            }
+        }
+    }
+
+    // name the groups
+    let names: Vec<String> = kmap.row_vars.iter().chain(&kmap.col_vars).cloned().collect();
+    for (i, group) in kmap.groups.iter().enumerate() {
+        let product = sum_of_products(&names, &[group.term(names.len())]);
+        output.writeln(format_args!("{}: {}", group_letter(i), product))?;
+    }
+    return Ok(());
+}
@@ -0,0 +1,69 @@
+
+// uses
+use std::io;
+use crate::table::TruthTable;
+use crate::util::Output;
+
+/**
+ * options for latex tables, which default to no rules
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct LatexOptions {
+
+    // draw a vertical rule between input and derived columns
+    pub rule: bool
+}
+
+/**
+ * escapes text for latex text mode
+ */
+pub fn escape(text: &str) -> String {
+    let mut escaped = String::new();
+    for c in text.chars() {
+        match c {
+            '&' | '%' | '$' | '#' | '_' | '{' | '}' => {
+                escaped.push('\\');
+                escaped.push(c);
+            },
+            '\\' => escaped += "\\textbackslash{}",
+            '~' => escaped += "\\textasciitilde{}",
+            '^' => escaped += "\\textasciicircum{}",
+            '<' => escaped += "\\textless{}",
+            '>' => escaped += "\\textgreater{}",
+            '|' => escaped += "\\textbar{}",
+            '⊤' => escaped += "$\\top$",
+            '⊥' => escaped += "$\\bot$",
+            c => escaped.push(c)
+        }
+    }
+    return escaped;
+}
+
+/**
+ * writes a table as a latex tabular
+ */
+pub fn write_table(output: &mut Output, table: &TruthTable) -> io::Result<()> {
+    let options = output.latex;
+
+    // build the column spec, with rules where inputs meet derived columns
+    let mut spec = String::new();
+    for (i, column) in table.columns.iter().enumerate() {
+        if options.rule && i > 0 && table.columns[i-1].is_input() != column.is_input() {
+            spec.push('|');
+        }
+        spec.push('c');
+    }
+    output.writeln(format_args!("\\begin{{tabular}}{{{}}}", spec))?;
+
+    // write the header
+    let header: Vec<String> = table.columns.iter().map(|column| escape(&column.name)).collect();
+    output.writeln(format_args!("{} \\\\", header.join(" & ")))?;
+    output.writeln("\\hline")?;
+
+    // write every row
+    for row in &table.rows {
+        let vals: Vec<String> = row.iter().map(|val| escape(output.symbols.of(*val))).collect();
+        output.writeln(format_args!("{} \\\\", vals.join(" & ")))?;
+    }
+    return output.writeln("\\end{tabular}");
+}
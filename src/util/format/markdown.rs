@@ -0,0 +1,51 @@
+
+// uses
+use std::io;
+use crate::table::TruthTable;
+use crate::util::Output;
+
+// chars that markdown would otherwise treat as formatting
+const SPECIAL: [char;10] = ['\\','`','*','_','|','[',']','<','>','~'];
+
+/**
+ * escapes text so it shows up as written
+ */
+pub fn escape(text: &str) -> String {
+    let mut escaped = String::new();
+    for c in text.chars() {
+        if SPECIAL.contains(&c) {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    return escaped;
+}
+
+/**
+ * writes a table as a GitHub flavoured markdown table
+ */
+pub fn write_table(output: &mut Output, table: &TruthTable) -> io::Result<()> {
+
+    // write the header and the alignment row
+    output.write('|')?;
+    for column in &table.columns {
+        output.write(format_args!(" {} |", escape(&column.name)))?;
+    }
+    output.writeln("")?;
+    output.write('|')?;
+    for _ in &table.columns {
+        output.write(":-:|")?;
+    }
+    output.writeln("")?;
+
+    // write every row
+    for row in &table.rows {
+        output.write('|')?;
+        for val in row {
+            let symbol = escape(output.symbols.of(*val));
+            output.write(format_args!(" {} |", symbol))?;
+        }
+        output.writeln("")?;
+    }
+    return Ok(());
+}
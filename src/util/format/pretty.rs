@@ -0,0 +1,108 @@
+
+// uses
+use std::io;
+use crate::table::{Column, TruthTable};
+use crate::util::Output;
+use crate::value::Value;
+
+// ansi colours for true, false and don't care cells
+const GREEN: &str = "\x1b[32m";
+const RED: &str = "\x1b[31m";
+const YELLOW: &str = "\x1b[33m";
+const RESET: &str = "\x1b[0m";
+
+/**
+ * box drawing chars for one horizontal border
+ */
+struct Border {
+    left: char,
+    line: char,
+    cross: char,
+
+    // where input columns meet derived columns
+    split: char,
+    right: char
+}
+
+const TOP: Border = Border { left: '┌', line: '─', cross: '┬', split: '╥', right: '┐' };
+const MIDDLE: Border = Border { left: '├', line: '─', cross: '┼', split: '╫', right: '┤' };
+const BOTTOM: Border = Border { left: '└', line: '─', cross: '┴', split: '╨', right: '┘' };
+
+/**
+ * checks if a column starts a new group of input or derived columns
+ */
+fn splits(columns: &[Column], i: usize) -> bool {
+    i > 0 && columns[i-1].is_input() != columns[i].is_input()
+}
+
+/**
+ * writes a horizontal border
+ */
+fn write_border(output: &mut Output, columns: &[Column], widths: &[usize], border: &Border) -> io::Result<()> {
+    let mut line = String::new();
+    line.push(border.left);
+    for (i, width) in widths.iter().enumerate() {
+        if i > 0 {
+            line.push(if splits(columns, i) { border.split } else { border.cross });
+        }
+        line.extend(std::iter::repeat_n(border.line, width + 2));
+    }
+    line.push(border.right);
+    return output.writeln(line);
+}
+
+/**
+ * writes a row of cells centered in their columns
+ */
+fn write_cells(output: &mut Output, columns: &[Column], widths: &[usize], cells: &[(String, Option<&str>)]) -> io::Result<()> {
+    output.write('│')?;
+    for (i, (cell, color)) in cells.iter().enumerate() {
+        if i > 0 {
+            output.write(if splits(columns, i) { '║' } else { '│' })?;
+        }
+
+        // pad the cell on both sides
+        let space = widths[i] - cell.chars().count();
+        let left = space / 2;
+        output.write(" ".repeat(left + 1))?;
+        match color {
+            None => output.write(cell)?,
+            Some(color) => output.write(format_args!("{}{}{}", color, cell, RESET))?
+        }
+        output.write(" ".repeat(space - left + 1))?;
+    }
+    return output.writeln('│');
+}
+
+/**
+ * writes a table with aligned columns and box drawn borders
+ */
+pub fn write_table(output: &mut Output, table: &TruthTable) -> io::Result<()> {
+    let columns = &table.columns;
+
+    // every column is as wide as its widest cell
+    let symbol_width = [&output.symbols.t, &output.symbols.f, &output.symbols.x].iter().map(|symbol| symbol.chars().count()).max().unwrap_or(1);
+    let widths: Vec<usize> = columns.iter().map(|column| column.name.chars().count().max(symbol_width)).collect();
+
+    // write the header
+    write_border(output, columns, &widths, &TOP)?;
+    let header: Vec<(String, Option<&str>)> = columns.iter().map(|column| (column.name.clone(), None)).collect();
+    write_cells(output, columns, &widths, &header)?;
+    write_border(output, columns, &widths, &MIDDLE)?;
+
+    // write every row
+    for row in &table.rows {
+        let cells: Vec<(String, Option<&str>)> = row.iter().map(|val| {
+            let color = match val {
+                _ if !output.color => None,
+                Value::True => Some(GREEN),
+                Value::False => Some(RED),
+                Value::DontCare => Some(YELLOW)
+            };
+            (output.symbols.of(*val).to_string(), color)
+        }).collect();
+        write_cells(output, columns, &widths, &cells)?;
+    }
+
+    return write_border(output, columns, &widths, &BOTTOM);
+}
@@ -0,0 +1,38 @@
+
+// uses
+use std::io;
+use crate::table::TruthTable;
+use crate::util::Output;
+use crate::value::Value;
+
+/**
+ * writes a table as comma and tab separated text, header first
+ */
+pub fn write_table(output: &mut Output, table: &TruthTable) -> io::Result<()> {
+
+    // print the header
+    for (i, column) in table.columns.iter().enumerate() {
+        if i > 0 {
+            output.write(",\t")?;
+        }
+        output.write(&column.name)?;
+    }
+    output.writeln("")?;
+
+    // print every row
+    for row in &table.rows {
+        for (i, val) in row.iter().enumerate() {
+            if i > 0 {
+                output.write(",\t")?;
+            }
+            match val {
+                Value::True => output.write_true()?,
+                Value::False => output.write_false()?,
+                Value::DontCare => output.write_dont_care()?
+            }
+        }
+        output.writeln("")?;
+    }
+
+    return output.writeln("Done!");
+}
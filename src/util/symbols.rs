@@ -0,0 +1,82 @@
+
+// uses
+use crate::value::Value;
+use super::tokenize::clashes;
+
+/**
+ * the symbols written and read for true, false and don't care
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Symbols {
+    pub t: String,
+    pub f: String,
+    pub x: String
+}
+
+/**
+ * defaults to the digits scripts have always used
+ */
+impl Default for Symbols {
+    fn default() -> Symbols {
+        Symbols::new("1", "0").unwrap()
+    }
+}
+
+/**
+ * impl block for symbols
+ */
+impl Symbols {
+
+    // creates symbols with X for don't care, if they are valid
+    pub fn new(t: &str, f: &str) -> Option<Symbols> {
+        Symbols::with_dont_care(t, f, "X")
+    }
+
+    // creates symbols, if they are non-empty, distinct and read as no operator, separator or comment
+    pub fn with_dont_care(t: &str, f: &str, x: &str) -> Option<Symbols> {
+        let valid = |symbol: &str| !symbol.is_empty() && !symbol.chars().any(char::is_whitespace) && !clashes(symbol);
+        if !valid(t) || !valid(f) || !valid(x) || t == f || t == x || f == x {
+            return None;
+        }
+        return Some(Symbols {
+            t: t.to_string(),
+            f: f.to_string(),
+            x: x.to_string()
+        });
+    }
+
+    /**
+     * gets symbols from a preset name, or a comma separated pair with an optional don't care
+     */
+    pub fn from_name(name: &str) -> Option<Symbols> {
+        match name {
+            "digits" => Symbols::new("1", "0"),
+            "letters" => Symbols::new("T", "F"),
+            "logic" => Symbols::new("⊤", "⊥"),
+            "levels" => Symbols::new("H", "L"),
+            list => match list.split(',').collect::<Vec<&str>>()[..] {
+                [t, f] => Symbols::new(t, f),
+                [t, f, x] => Symbols::with_dont_care(t, f, x),
+                _ => None
+            }
+        }
+    }
+
+    /**
+     * gets the value a symbol stands for
+     */
+    pub fn value(&self, symbol: &str) -> Option<Value> {
+        [Value::True, Value::False, Value::DontCare].iter().copied().find(|val| self.of(*val) == symbol)
+    }
+
+    /**
+     * gets the symbol for a value
+     */
+    pub fn of(&self, val: Value) -> &str {
+        match val {
+            Value::True => &self.t,
+            Value::False => &self.f,
+            Value::DontCare => &self.x
+        }
+    }
+}
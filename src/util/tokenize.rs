@@ -17,329 +17,485 @@ pub const NEG_EQUIVALENCE: &str = "=!";
 pub const OPEN: &str = "(";
 pub const CLOSE: &str = ")";
 
+// values
+pub const DONT_CARE: &str = "-";
+
+// comments, either to the end of the line or between brackets
+pub const LINE_COMMENT: &str = "%";
+pub const OPEN_COMMENT: &str = "[";
+pub const CLOSE_COMMENT: &str = "]";
+
+// alternate notations for operators, longest first so `<->` is not read as `<` then `->`
+const ALIASES: [(&str, &str);11] = [
+    ("<->", EQUIVALENCE),
+    ("->", IMPLICATION),
+    ("&&", CONJUNCTION),
+    ("||", DISJUNCTION),
+    ("^", EX_DISJUNCTION),
+    ("∧", CONJUNCTION),
+    ("∨", DISJUNCTION),
+    ("¬", PRE_NEGATION),
+    ("⊕", EX_DISJUNCTION),
+    ("→", IMPLICATION),
+    ("↔", EQUIVALENCE)
+];
+
+// english keywords for operators, which can't be used as variable names
+const KEYWORDS: [(&str, &str);6] = [
+    ("and", CONJUNCTION),
+    ("or", DISJUNCTION),
+    ("not", PRE_NEGATION),
+    ("xor", EX_DISJUNCTION),
+    ("implies", IMPLICATION),
+    ("iff", EQUIVALENCE)
+];
 
 
 
-// uses
-use std::io;
 
-// constant variables
-const WHITESPACE: [char;3] = [' ','\t','\n'];
+// uses
+use std::fmt;
+use unicode_xid::UnicodeXID;
+use super::symbols::Symbols;
+use crate::script::expr::Span;
+use crate::value::Value;
 
 /**
- * checks if the char is whitespace
+ * checks if the char is whitespace, including unicode spaces like the no-break space
  */
 pub fn is_whitespace(c:char)->bool {
-    WHITESPACE.iter().any(|ws|*ws==c)
+    c.is_whitespace()
 }
 
 /**
- * checks if the char is in the alphabet
+ * checks if the char can start a variable, which is any unicode letter or an underscore
  */
-pub fn is_alpha(c:char)->bool {
-    let val = c as u8;
-    return (val>=65 && val<=90) || (val>=97 && val<=122);
+pub fn is_var_start(c:char)->bool {
+    c.is_xid_start() || c == '_'
 }
 
 /**
- * checks if the char is alphanumeric
+ * checks if the char can continue a variable
  */
-pub fn is_alphanum(c:char)->bool {
-    let val = c as u8;
-    return (val>=65 && val<=90) || (val>=97 && val<=122) || (val>=48 && val<=57) || val == 95;
+pub fn is_var_char(c:char)->bool {
+    c.is_xid_continue()
 }
 
 /**
- * token enum for tokenizing
+ * token enum for tokenizing, borrowing variables from the source
  */
-pub enum Token {
-    Val(bool),
-    Var(String),
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Token<'a> {
+    Val(Value),
+    Var(&'a str),
     Op(&'static str),
     EndLine,
-    EndScript
+    EndScript,
+
+    // a comment with its markers, kept so tools can rewrite a script without losing it
+    Comment(&'a str)
 }
 
 /**
- * Returns the precidence of the operator.
+ * displays the token as it appears in a script
  */
-pub fn prec(op:&str) -> io::Result<i32> {
-    match op {
-        ASSIGNMENT=>Ok(0),
-        PRE_NEGATION=>Ok(1),
-        DISJUNCTION=>Ok(2),
-        NEG_DISJUNCTION=>Ok(2),
-        CONJUNCTION=>Ok(3),
-        NEG_CONJUNCTION=>Ok(3),
-        EX_DISJUNCTION=>Ok(4),
-        NEG_EX_DISJUNCTION=>Ok(4),
-        IMPLICATION=>Ok(5),
-        NEG_IMPLICATION=>Ok(5),
-        EQUIVALENCE=>Ok(6),
-        NEG_EQUIVALENCE=>Ok(6),
-        POST_VAL_NEGATION=>Ok(7),
-        POST_OP_NEGATION=>Ok(7),
-        OPEN=>Ok(8),
-        CLOSE=>Ok(-1),
-        _=> {
-            return Err(io::Error::new(io::ErrorKind::Other, format!("unrecognized operator {}", op)));
+impl fmt::Display for Token<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Token::Val(Value::True) => write!(f, "1"),
+            Token::Val(Value::False) => write!(f, "0"),
+            Token::Val(Value::DontCare) => write!(f, "{}", DONT_CARE),
+            Token::Var(var) => write!(f, "{}", var),
+            Token::Op(op) => write!(f, "{}", op),
+            Token::EndLine => write!(f, ","),
+            Token::EndScript => write!(f, "."),
+            Token::Comment(comment) => write!(f, "{}", comment)
         }
     }
 }
 
-
 /**
- * Flips the operator
+ * Returns the precidence of the operator.
  */
-pub fn flip(op:&str) -> io::Result<&str> {
-    Ok(match op {
-        DISJUNCTION=>NEG_DISJUNCTION,
-        NEG_DISJUNCTION=>DISJUNCTION,
-        CONJUNCTION=>NEG_CONJUNCTION,
-        NEG_CONJUNCTION=>CONJUNCTION,
-        EX_DISJUNCTION=>NEG_EX_DISJUNCTION,
-        NEG_EX_DISJUNCTION=>EX_DISJUNCTION,
-        IMPLICATION=>NEG_IMPLICATION,
-        NEG_IMPLICATION=>IMPLICATION,
-        EQUIVALENCE=>NEG_EQUIVALENCE,
-        NEG_EQUIVALENCE=>EQUIVALENCE,
-        _=> {
-            return Err(io::Error::new(io::ErrorKind::Other, "operator can't be negated"));
-        }
-    })
+pub fn prec(op:&str) -> Option<i32> {
+    match op {
+        ASSIGNMENT=>Some(0),
+        PRE_NEGATION=>Some(1),
+        DISJUNCTION=>Some(2),
+        NEG_DISJUNCTION=>Some(2),
+        CONJUNCTION=>Some(3),
+        NEG_CONJUNCTION=>Some(3),
+        EX_DISJUNCTION=>Some(4),
+        NEG_EX_DISJUNCTION=>Some(4),
+        IMPLICATION=>Some(5),
+        NEG_IMPLICATION=>Some(5),
+        EQUIVALENCE=>Some(6),
+        NEG_EQUIVALENCE=>Some(6),
+        POST_VAL_NEGATION=>Some(7),
+        POST_OP_NEGATION=>Some(7),
+        OPEN=>Some(8),
+        CLOSE=>Some(-1),
+        _=>None
+    }
 }
 
 
 /**
- * gets a token from the string, shrinking the sring in the process
+ * which side a chain of an operator groups from
  */
-pub fn get_token(script: &mut String)->Option<Token> {
-
-    // iterate over the chars in the string
-    for i in 0..script.len() {
-        let c = script.as_bytes()[i] as char;
-
-        // if whitespace
-        if is_whitespace(c) {
-            continue;
-        }
-
-        // if is the beginning of a variable
-        if is_alpha(c) {
-
-            // create the var string
-            let mut var_string = String::new();
-            let mut rest = String::new();
-            let mut in_var = true;
-
-            // get the variable and the rest of the script
-            for j in i..script.len() {
-                let c = script.as_bytes()[j] as char;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Assoc {
+    Left,
+    Right
+}
 
-                // if not alphanum
-                if !is_alphanum(c) {
-                    in_var = false;
-                }
+/**
+ * Returns the associativity of the binary operator.
+ */
+pub fn assoc(op:&str) -> Option<Assoc> {
+    match op {
+        DISJUNCTION=>Some(Assoc::Left),
+        NEG_DISJUNCTION=>Some(Assoc::Left),
+        CONJUNCTION=>Some(Assoc::Left),
+        NEG_CONJUNCTION=>Some(Assoc::Left),
+        EX_DISJUNCTION=>Some(Assoc::Left),
+        NEG_EX_DISJUNCTION=>Some(Assoc::Left),
+        IMPLICATION=>Some(Assoc::Left),
+        NEG_IMPLICATION=>Some(Assoc::Left),
+        EQUIVALENCE=>Some(Assoc::Left),
+        NEG_EQUIVALENCE=>Some(Assoc::Left),
+        _=>None
+    }
+}
 
-                // if in the variable
-                if in_var {
-                    var_string.push(c)
-                }
+/**
+ * Returns the associativity of the binary operator in the conventional ranking,
+ * where implication groups from the right
+ */
+pub fn conventional_assoc(op:&str) -> Option<Assoc> {
+    match op {
+        IMPLICATION=>Some(Assoc::Right),
+        NEG_IMPLICATION=>Some(Assoc::Right),
+        _=>assoc(op)
+    }
+}
 
-                // if not in the variable
-                else {
-                    rest.push(c);
-                }
-            }
+/**
+ * Returns the precidence of the operator in the conventional ranking,
+ * from equivalence loosest to conjunction tightest
+ */
+pub fn conventional_prec(op:&str) -> Option<i32> {
+    match op {
+        EQUIVALENCE=>Some(2),
+        NEG_EQUIVALENCE=>Some(2),
+        IMPLICATION=>Some(3),
+        NEG_IMPLICATION=>Some(3),
+        DISJUNCTION=>Some(4),
+        NEG_DISJUNCTION=>Some(4),
+        EX_DISJUNCTION=>Some(5),
+        NEG_EX_DISJUNCTION=>Some(5),
+        CONJUNCTION=>Some(6),
+        NEG_CONJUNCTION=>Some(6),
+        _=>prec(op)
+    }
+}
 
-            // return the values
-            *script = rest;
-            return Some(Token::Var(var_string))
-        }
+/**
+ * the ways binary operators can be ranked against each other
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Precedence {
 
-        // if equivalence
-        if c == '=' {
+    // the ranking scripts have always used
+    #[default]
+    Legacy,
 
-            // create a new string without the token
-            let mut rest = String::new();
-            for j in i+1..script.len() {
-                rest.push(script.as_bytes()[j] as char)
-            }
+    // the ranking of textbooks, where only implication associates right
+    Conventional
+}
 
-            *script = rest;
-            return Some(Token::Op(EQUIVALENCE));
+/**
+ * impl block for precedence
+ */
+impl Precedence {
+
+    /**
+     * gets a precedence from its name
+     */
+    pub fn from_name(name: &str) -> Option<Precedence> {
+        match name {
+            "legacy" => Some(Precedence::Legacy),
+            "conventional" => Some(Precedence::Conventional),
+            _ => None
         }
+    }
 
-        // if implication
-        if c == '>' {
-
-            // create a new string without the token
-            let mut rest = String::new();
-            for j in i+1..script.len() {
-                rest.push(script.as_bytes()[j] as char)
-            }
-
-            *script = rest;
-            return Some(Token::Op(IMPLICATION));
+    /**
+     * gets the precidence of the operator
+     */
+    pub fn prec(self, op: &str) -> Option<i32> {
+        match self {
+            Precedence::Legacy => prec(op),
+            Precedence::Conventional => conventional_prec(op)
         }
+    }
 
-        // if exclusive disjunction
-        if c == '@' {
-
-            // create a new string without the token
-            let mut rest = String::new();
-            for j in i+1..script.len() {
-                rest.push(script.as_bytes()[j] as char)
-            }
-
-            *script = rest;
-            return Some(Token::Op(EX_DISJUNCTION));
+    /**
+     * gets the associativity of the binary operator
+     */
+    pub fn assoc(self, op: &str) -> Option<Assoc> {
+        match self {
+            Precedence::Legacy => assoc(op),
+            Precedence::Conventional => conventional_assoc(op)
         }
+    }
+}
 
-        // if conjunction
-        if c == '*' {
-
-            // create a new string without the token
-            let mut rest = String::new();
-            for j in i+1..script.len() {
-                rest.push(script.as_bytes()[j] as char)
-            }
-
-            *script = rest;
-            return Some(Token::Op(CONJUNCTION));
-        }
+/**
+ * Flips the operator, if it can be negated
+ */
+pub fn flip(op:&str) -> Option<&'static str> {
+    Some(match op {
+        DISJUNCTION=>NEG_DISJUNCTION,
+        NEG_DISJUNCTION=>DISJUNCTION,
+        CONJUNCTION=>NEG_CONJUNCTION,
+        NEG_CONJUNCTION=>CONJUNCTION,
+        EX_DISJUNCTION=>NEG_EX_DISJUNCTION,
+        NEG_EX_DISJUNCTION=>EX_DISJUNCTION,
+        IMPLICATION=>NEG_IMPLICATION,
+        NEG_IMPLICATION=>IMPLICATION,
+        EQUIVALENCE=>NEG_EQUIVALENCE,
+        NEG_EQUIVALENCE=>EQUIVALENCE,
+        _=>return None
+    })
+}
 
-        // if disjunction
-        if c == '+' {
 
-            // create a new string without the token
-            let mut rest = String::new();
-            for j in i+1..script.len() {
-                rest.push(script.as_bytes()[j] as char)
-            }
+/**
+ * gets the value of the symbol at the start of the string, and the rest of the string
+ */
+fn get_symbol<'a>(script: &'a str, symbols: &Symbols) -> Option<(Value, &'a str)> {
+    for val in [Value::True, Value::False, Value::DontCare] {
+        let symbol = symbols.of(val);
 
-            *script = rest;
-            return Some(Token::Op(DISJUNCTION));
+        // a symbol that reads like a variable is left for the parser, so it can still be declared
+        if is_word(symbol) {
+            continue;
         }
+        if let Some(rest) = script.strip_prefix(symbol) {
 
-        // if assignment
-        if c == ':' {
-
-            // create a new string without the token
-            let mut rest = String::new();
-            for j in i+1..script.len() {
-                rest.push(script.as_bytes()[j] as char)
+            // a symbol that could be part of a variable must not be followed by more of one
+            if !(symbol.ends_with(is_var_char) && rest.starts_with(is_var_char)) {
+                return Some((val, rest));
             }
-
-            *script = rest;
-            return Some(Token::Op(ASSIGNMENT));
         }
+    }
+    return None;
+}
 
-        // if prefix negation
-        if c == '~' {
-
-            // create a new string without the token
-            let mut rest = String::new();
-            for j in i+1..script.len() {
-                rest.push(script.as_bytes()[j] as char)
-            }
+/**
+ * checks if the text reads as a single variable
+ */
+pub fn is_word(text: &str) -> bool {
+    text.starts_with(is_var_start) && text.chars().all(is_var_char)
+}
 
-            *script = rest;
-            return Some(Token::Op(PRE_NEGATION));
+/**
+ * checks if any part of a symbol for a value would be read as an operator, a separator or a comment,
+ * could cut an alternate notation short, since symbols are read first, or is the don't care literal
+ */
+pub fn clashes(symbol: &str) -> bool {
+    if symbol == DONT_CARE {
+        return true;
+    }
+    let mut rest = symbol;
+    while let Some(c) = rest.chars().next() {
+        let alias = ALIASES.iter().any(|(alias, _)| rest.starts_with(alias) || alias.starts_with(rest));
+        if alias || rest.starts_with(LINE_COMMENT) || rest.starts_with(OPEN_COMMENT) {
+            return true;
         }
-
-        // if postfix variable negation
-        if c == '\'' {
-
-            // create a new string without the token
-            let mut rest = String::new();
-            for j in i+1..script.len() {
-                rest.push(script.as_bytes()[j] as char)
-            }
-
-            *script = rest;
-            return Some(Token::Op(POST_VAL_NEGATION));
+        let len = match is_var_start(c) {
+            true => rest.char_indices().skip(1).find(|(_, c)| !is_var_char(*c)).map(|(i, _)| i).unwrap_or(rest.len()),
+            false => c.len_utf8()
+        };
+        let token = match keyword(&rest[..len]) {
+            Some(op) => Some(Token::Op(op)),
+            None => char_token(c)
+        };
+        if matches!(token, Some(Token::Op(_)) | Some(Token::EndLine) | Some(Token::EndScript)) {
+            return true;
         }
+        rest = &rest[len..];
+    }
+    return false;
+}
 
-        // if postfix operator negation
-        if c == '!' {
+/**
+ * gets the operator an alternate notation at the start of the string stands for, and the rest of the string
+ */
+fn get_alias(script: &str) -> Option<(&'static str, &str)> {
+    ALIASES.iter().find_map(|(alias, op)| script.strip_prefix(alias).map(|rest| (*op, rest)))
+}
 
-            // create a new string without the token
-            let mut rest = String::new();
-            for j in i+1..script.len() {
-                rest.push(script.as_bytes()[j] as char)
-            }
+/**
+ * gets the operator a keyword stands for
+ */
+pub fn keyword(word: &str) -> Option<&'static str> {
+    KEYWORDS.iter().find(|(keyword, _)| *keyword == word).map(|(_, op)| *op)
+}
 
-            *script = rest;
-            return Some(Token::Op(POST_OP_NEGATION));
-        }
+/**
+ * a token or other value with the span of source it came from
+ */
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Spanned<T> {
+    pub node: T,
+    pub span: Span
+}
 
-        // if open parenthesis
-        if c == '(' {
+/**
+ * a char that starts no token
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LexError {
+    pub c: char,
+    pub span: Span
+}
 
-            // create a new string without the token
-            let mut rest = String::new();
-            for j in i+1..script.len() {
-                rest.push(script.as_bytes()[j] as char)
-            }
+/**
+ * displays the lex error as a message
+ */
+impl fmt::Display for LexError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "unexpected character `{}`", self.c.escape_debug())
+    }
+}
 
-            *script = rest;
-            return Some(Token::Op(OPEN));
-        }
+/**
+ * gets the token of a char that is a token on its own
+ */
+fn char_token(c: char) -> Option<Token<'static>> {
+    Some(match c {
+        '=' => Token::Op(EQUIVALENCE),
+        '>' => Token::Op(IMPLICATION),
+        '@' => Token::Op(EX_DISJUNCTION),
+        '*' => Token::Op(CONJUNCTION),
+        '+' => Token::Op(DISJUNCTION),
+        ':' => Token::Op(ASSIGNMENT),
+        '~' => Token::Op(PRE_NEGATION),
+        '\'' => Token::Op(POST_VAL_NEGATION),
+        '!' => Token::Op(POST_OP_NEGATION),
+        '(' => Token::Op(OPEN),
+        ')' => Token::Op(CLOSE),
+        ',' => Token::EndLine,
+        '.' => Token::EndScript,
+        '1' => Token::Val(Value::True),
+        '0' => Token::Val(Value::False),
+        '-' => Token::Val(Value::DontCare),
+        _ => return None
+    })
+}
 
-        // if close parenthesis
-        if c == ')' {
+/**
+ * gets the token at the start of a string, which must not start with whitespace, and its length in bytes
+ */
+fn scan<'a>(script: &'a str, symbols: &Symbols) -> (Option<Token<'a>>, usize) {
+    let len = |rest: &str| script.len() - rest.len();
 
-            // create a new string without the token
-            let mut rest = String::new();
-            for j in i+1..script.len() {
-                rest.push(script.as_bytes()[j] as char)
-            }
+    // if a comment, which runs to the end of the line or past the closing bracket, or else the script
+    let end = if script.starts_with(LINE_COMMENT) {
+        Some(script.find('\n').unwrap_or(script.len()))
+    }
+    else if script.starts_with(OPEN_COMMENT) {
+        Some(script.find(CLOSE_COMMENT).map(|i| i + CLOSE_COMMENT.len()).unwrap_or(script.len()))
+    }
+    else {
+        None
+    };
+    if let Some(end) = end {
+        return (Some(Token::Comment(&script[..end])), end);
+    }
 
-            *script = rest;
-            return Some(Token::Op(CLOSE));
-        }
+    // if the chosen symbol for a value
+    if let Some((val, rest)) = get_symbol(script, symbols) {
+        return (Some(Token::Val(val)), len(rest));
+    }
 
-        // if end line
-        if c == ',' {
+    // if an alternate notation for an operator
+    if let Some((op, rest)) = get_alias(script) {
+        return (Some(Token::Op(op)), len(rest));
+    }
 
-            // create a new string without the token
-            let mut rest = String::new();
-            for j in i+1..script.len() {
-                rest.push(script.as_bytes()[j] as char)
-            }
+    // if a variable or a keyword
+    let c = script.chars().next().unwrap();
+    if is_var_start(c) {
+        let end = script.char_indices().skip(1).find(|(_, c)| !is_var_char(*c)).map(|(i, _)| i).unwrap_or(script.len());
+        let var = &script[..end];
+        return match keyword(var) {
+            Some(op) => (Some(Token::Op(op)), end),
+            None => (Some(Token::Var(var)), end)
+        };
+    }
 
-            *script = rest;
-            return Some(Token::EndLine);
-        }
+    // if an operator, a value or the end of a line or the script
+    return (char_token(c), c.len_utf8());
+}
 
-        // if end script
-        if c == '.' {
+/**
+ * splits a source into tokens without copying it, keeping the byte offsets of each and its comments
+ */
+#[derive(Debug, Clone)]
+pub struct Lexer<'a> {
+    source: &'a str,
+    symbols: &'a Symbols,
 
-            // create a new string without the token
-            let mut rest = String::new();
-            for j in i+1..script.len() {
-                rest.push(script.as_bytes()[j] as char)
-            }
+    // the byte offset of the rest of the source
+    pos: usize
+}
 
-            *script = rest;
-            return Some(Token::EndScript);
-        }
+/**
+ * impl block for lexer
+ */
+impl<'a> Lexer<'a> {
 
-        // if c is a boolean value
-        if c == '1' || c == '0' {
+    // creates a lexer at the start of a source
+    pub fn new(source: &'a str, symbols: &'a Symbols) -> Lexer<'a> {
+        return Lexer { source, symbols, pos: 0 };
+    }
 
-            let l = script.len();
-            *script = script[1..l].to_string();
-            if c== '1' {
-                return Some(Token::Val(true));
-            }
-            if c=='0' {
-                return Some(Token::Val(false));
-            }
-        }
-        
+    /**
+     * gets the byte offset the next token is looked for at
+     */
+    pub fn offset(&self) -> usize {
+        self.pos
     }
+}
 
-    return None;
-}
\ No newline at end of file
+/**
+ * yields every token of the source, or an error for each char that starts none
+ */
+impl<'a> Iterator for Lexer<'a> {
+    type Item = Result<Spanned<Token<'a>>, LexError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let rest = &self.source[self.pos..];
+
+        // skip whitespace
+        let start = match rest.char_indices().find(|(_, c)| !is_whitespace(*c)) {
+            None => {
+                self.pos = self.source.len();
+                return None;
+            },
+            Some((i, _)) => self.pos + i
+        };
+
+        // take the token
+        let (token, len) = scan(&self.source[start..], self.symbols);
+        self.pos = start + len;
+        let span = Span::new(start, self.pos);
+        return Some(match token {
+            None => Err(LexError { c: self.source[start..].chars().next().unwrap(), span }),
+            Some(node) => Ok(Spanned { node, span })
+        });
+    }
+}
@@ -0,0 +1,125 @@
+
+// uses
+use std::ops::{BitAnd, BitOr, BitXor, Not};
+
+/**
+ * the value of a cell, which is either true, false or don't care
+ *
+ * don't care propagates like an unknown value: it only disappears when the
+ * other operand decides the result on its own, so `X * 0` is 0 and `X + 1` is 1
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Value {
+    False,
+    True,
+    DontCare
+}
+
+/**
+ * impl block for values
+ */
+impl Value {
+
+    /**
+     * gets the boolean of the value, if it is not don't care
+     */
+    pub fn to_bool(self) -> Option<bool> {
+        match self {
+            Value::False => Some(false),
+            Value::True => Some(true),
+            Value::DontCare => None
+        }
+    }
+
+    /**
+     * checks if the value is true
+     */
+    pub fn is_true(self) -> bool {
+        self == Value::True
+    }
+
+    /**
+     * checks if the value is false
+     */
+    pub fn is_false(self) -> bool {
+        self == Value::False
+    }
+
+    /**
+     * checks if the value is don't care
+     */
+    pub fn is_dont_care(self) -> bool {
+        self == Value::DontCare
+    }
+
+    /**
+     * checks if two values can be the same, which don't care always can
+     */
+    pub fn agrees(self, other: Value) -> bool {
+        self == other || self.is_dont_care() || other.is_dont_care()
+    }
+}
+
+/**
+ * converts a boolean to a value
+ */
+impl From<bool> for Value {
+    fn from(val: bool) -> Value {
+        if val { Value::True } else { Value::False }
+    }
+}
+
+/**
+ * negation, which leaves don't care alone
+ */
+impl Not for Value {
+    type Output = Value;
+    fn not(self) -> Value {
+        match self {
+            Value::False => Value::True,
+            Value::True => Value::False,
+            Value::DontCare => Value::DontCare
+        }
+    }
+}
+
+/**
+ * conjunction, which is false if either value is
+ */
+impl BitAnd for Value {
+    type Output = Value;
+    fn bitand(self, other: Value) -> Value {
+        match (self, other) {
+            (Value::False, _) | (_, Value::False) => Value::False,
+            (Value::True, Value::True) => Value::True,
+            _ => Value::DontCare
+        }
+    }
+}
+
+/**
+ * disjunction, which is true if either value is
+ */
+impl BitOr for Value {
+    type Output = Value;
+    fn bitor(self, other: Value) -> Value {
+        match (self, other) {
+            (Value::True, _) | (_, Value::True) => Value::True,
+            (Value::False, Value::False) => Value::False,
+            _ => Value::DontCare
+        }
+    }
+}
+
+/**
+ * exclusive disjunction, which needs both values
+ */
+impl BitXor for Value {
+    type Output = Value;
+    fn bitxor(self, other: Value) -> Value {
+        match (self.to_bool(), other.to_bool()) {
+            (Some(a), Some(b)) => Value::from(a != b),
+            _ => Value::DontCare
+        }
+    }
+}
@@ -0,0 +1,40 @@
+#![allow(clippy::needless_return)]
+
+// uses
+use ttable::Script;
+use ttable::equiv::{equivalent, Equivalence};
+use ttable::script::parse::ParseOptions;
+
+/**
+ * parses an expression to compare, declaring its variables as they are used
+ */
+fn parse(source: &str) -> Script {
+    let options = ParseOptions { declare_free: true, ..ParseOptions::default() };
+    return Script::parse_with(source, &options).unwrap();
+}
+
+/**
+ * checks if two expressions to compare are equivalent
+ */
+fn same(left: &str, right: &str) -> bool {
+    let left = parse(left);
+    let right = parse(right);
+    left.result().unwrap();
+    right.result().unwrap();
+    return equivalent(&left, &right).unwrap() == Equivalence::Equivalent;
+}
+
+#[test]
+fn equal_to_a_value_is_an_expression_not_a_pin() {
+    assert!(parse("A = 1").lines.iter().all(|line| line.pin.is_none()));
+    assert!(same("A = 1", "A"));
+    assert!(same("A", "A = 1"));
+    assert!(!same("A * B", "A = 1"));
+}
+
+#[test]
+fn pins_still_declare_inputs_before_other_lines() {
+    let options = ParseOptions::default();
+    let script = Script::parse_with("A = 1, B, Z: A * B.", &options).unwrap();
+    assert_eq!(script.evaluate().unwrap().rows.len(), 2);
+}
@@ -0,0 +1,312 @@
+#![allow(clippy::needless_return)]
+
+// uses
+use ttable::{Script, Value};
+use ttable::script::parse::ParseOptions;
+use ttable::util::tokenize::Precedence;
+
+// inputs every generated expression can use, the first being the most significant
+const INPUTS: [&str;4] = ["A", "B", "C", "D"];
+
+// random expressions generated for each property
+const CASES: usize = 500;
+
+/**
+ * xorshift generator, so every run tests the same expressions
+ */
+struct Rng(u64);
+
+/**
+ * impl block for rng
+ */
+impl Rng {
+
+    /**
+     * gets a number below n
+     */
+    fn below(&mut self, n: usize) -> usize {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        return (self.0 % n as u64) as usize;
+    }
+
+    /**
+     * gets true with a chance of one in n
+     */
+    fn one_in(&mut self, n: usize) -> bool {
+        self.below(n) == 0
+    }
+}
+
+/**
+ * a binary operator of the reference implementation
+ */
+#[derive(Debug, Clone, Copy)]
+struct Op {
+    symbol: &'static str,
+    apply: fn(bool, bool) -> bool,
+
+    // rank in the legacy and conventional profiles, higher binding tighter
+    legacy: i32,
+    conventional: i32,
+
+    // whether chains group from the right in the conventional profile
+    right: bool
+}
+
+const OPS: [Op;10] = [
+    Op { symbol: "+", apply: |a, b| a || b, legacy: 2, conventional: 4, right: false },
+    Op { symbol: "+!", apply: |a, b| !(a || b), legacy: 2, conventional: 4, right: false },
+    Op { symbol: "*", apply: |a, b| a && b, legacy: 3, conventional: 6, right: false },
+    Op { symbol: "*!", apply: |a, b| !(a && b), legacy: 3, conventional: 6, right: false },
+    Op { symbol: "@", apply: |a, b| a != b, legacy: 4, conventional: 5, right: false },
+    Op { symbol: "@!", apply: |a, b| a == b, legacy: 4, conventional: 5, right: false },
+    Op { symbol: ">", apply: |a, b| !a || b, legacy: 5, conventional: 3, right: true },
+    Op { symbol: ">!", apply: |a, b| a && !b, legacy: 5, conventional: 3, right: true },
+    Op { symbol: "=", apply: |a, b| a == b, legacy: 6, conventional: 2, right: false },
+    Op { symbol: "=!", apply: |a, b| a != b, legacy: 6, conventional: 2, right: false }
+];
+
+/**
+ * an operand of the reference implementation
+ */
+#[derive(Debug, Clone)]
+enum Operand {
+    Input(usize),
+    Const(bool),
+    Not(Box<Operand>),
+    Group(Box<Tree>)
+}
+
+/**
+ * a binary expression of the reference implementation
+ */
+#[derive(Debug, Clone)]
+enum Tree {
+    Leaf(Operand),
+    Binary(Op, Box<Tree>, Box<Tree>)
+}
+
+/**
+ * generates an operand, possibly negated or a parenthesized tree
+ */
+fn gen_operand(rng: &mut Rng, depth: usize) -> Operand {
+    if rng.one_in(5) {
+        return Operand::Not(Box::new(gen_operand(rng, depth)));
+    }
+    if depth > 0 && rng.one_in(3) {
+        return Operand::Group(Box::new(gen_tree(rng, depth - 1)));
+    }
+    if rng.one_in(6) {
+        return Operand::Const(rng.one_in(2));
+    }
+    return Operand::Input(rng.below(INPUTS.len()));
+}
+
+/**
+ * generates a fully grouped tree
+ */
+fn gen_tree(rng: &mut Rng, depth: usize) -> Tree {
+    if depth == 0 || rng.one_in(3) {
+        return Tree::Leaf(gen_operand(rng, depth));
+    }
+    let op = OPS[rng.below(OPS.len())];
+    return Tree::Binary(op, Box::new(gen_tree(rng, depth - 1)), Box::new(gen_tree(rng, depth - 1)));
+}
+
+/**
+ * evaluates an operand for the inputs
+ */
+fn eval_operand(operand: &Operand, inputs: &[bool]) -> bool {
+    match operand {
+        Operand::Input(i) => inputs[*i],
+        Operand::Const(val) => *val,
+        Operand::Not(operand) => !eval_operand(operand, inputs),
+        Operand::Group(tree) => eval_tree(tree, inputs)
+    }
+}
+
+/**
+ * evaluates a tree for the inputs
+ */
+fn eval_tree(tree: &Tree, inputs: &[bool]) -> bool {
+    match tree {
+        Tree::Leaf(operand) => eval_operand(operand, inputs),
+        Tree::Binary(op, a, b) => (op.apply)(eval_tree(a, inputs), eval_tree(b, inputs))
+    }
+}
+
+/**
+ * writes an operand, negating with either ~ or '
+ */
+fn write_operand(operand: &Operand, rng: &mut Rng) -> String {
+    match operand {
+        Operand::Input(i) => INPUTS[*i].to_string(),
+        Operand::Const(val) => String::from(if *val { "1" } else { "0" }),
+        Operand::Not(operand) => {
+            let inner = write_operand(operand, rng);
+            if rng.one_in(2) { format!("~{}", inner) } else { format!("{}'", inner) }
+        },
+        Operand::Group(tree) => format!("({})", write_tree(tree, rng))
+    }
+}
+
+/**
+ * writes a tree with every binary expression in parentheses
+ */
+fn write_tree(tree: &Tree, rng: &mut Rng) -> String {
+    match tree {
+        Tree::Leaf(operand) => write_operand(operand, rng),
+        Tree::Binary(op, a, b) => format!("({} {} {})", write_tree(a, rng), op.symbol, write_tree(b, rng))
+    }
+}
+
+/**
+ * evaluates a chain of operands and operators without parentheses by splitting it at its
+ * loosest operator, the last one if it groups from the left and the first if from the right
+ */
+fn eval_chain(operands: &[bool], ops: &[Op], precedence: Precedence) -> bool {
+    if ops.is_empty() {
+        return operands[0];
+    }
+    let rank = |op: &Op| match precedence {
+        Precedence::Legacy => op.legacy,
+        Precedence::Conventional => op.conventional
+    };
+    let loosest = ops.iter().map(rank).min().unwrap();
+    let right = precedence == Precedence::Conventional && ops.iter().any(|op| rank(op) == loosest && op.right);
+    let split = match right {
+        true => ops.iter().position(|op| rank(op) == loosest).unwrap(),
+        false => ops.iter().rposition(|op| rank(op) == loosest).unwrap()
+    };
+    let left = eval_chain(&operands[..=split], &ops[..split], precedence);
+    let right = eval_chain(&operands[split + 1..], &ops[split + 1..], precedence);
+    return (ops[split].apply)(left, right);
+}
+
+/**
+ * gets the values of the inputs for a row of a table
+ */
+fn row_inputs(row: usize) -> Vec<bool> {
+    (0..INPUTS.len()).map(|i| (row >> (INPUTS.len() - 1 - i)) & 1 == 1).collect()
+}
+
+/**
+ * evaluates an expression over the inputs with the script evaluator
+ */
+fn evaluate(expr: &str, precedence: Precedence) -> Vec<Value> {
+    let source = format!("{}, Z: {}.", INPUTS.join(", "), expr);
+    let options = ParseOptions { precedence, ..ParseOptions::default() };
+    let script = Script::parse_with(&source, &options).unwrap_or_else(|e| panic!("{}", e.render("<test>", &source)));
+    let table = script.evaluate().unwrap();
+    let column = table.column("Z").unwrap();
+    return table.rows.iter().map(|row| row[column]).collect();
+}
+
+/**
+ * checks that an expression evaluates like the reference for every row
+ */
+fn assert_matches<F: Fn(&[bool]) -> bool>(expr: &str, precedence: Precedence, reference: F) {
+    for (row, val) in evaluate(expr, precedence).into_iter().enumerate() {
+        let inputs = row_inputs(row);
+        assert_eq!(val, Value::from(reference(&inputs)), "`{}` with {:?} for {:?}", expr, precedence, inputs);
+    }
+}
+
+#[test]
+fn grouped_trees_match_reference() {
+    let mut rng = Rng(0x9e37_79b9_7f4a_7c15);
+    for _ in 0..CASES {
+        let tree = gen_tree(&mut rng, 4);
+        let expr = write_tree(&tree, &mut rng);
+        for precedence in [Precedence::Legacy, Precedence::Conventional] {
+            assert_matches(&expr, precedence, |inputs| eval_tree(&tree, inputs));
+        }
+    }
+}
+
+#[test]
+fn chains_match_reference() {
+    let mut rng = Rng(0x2545_f491_4f6c_dd1d);
+    for _ in 0..CASES {
+        let len = 1 + rng.below(6);
+        let operands: Vec<Operand> = (0..=len).map(|_| gen_operand(&mut rng, 1)).collect();
+        let ops: Vec<Op> = (0..len).map(|_| OPS[rng.below(OPS.len())]).collect();
+
+        // write the chain without parentheses around its operators
+        let mut expr = write_operand(&operands[0], &mut rng);
+        for (op, operand) in ops.iter().zip(&operands[1..]) {
+            expr += &format!(" {} {}", op.symbol, write_operand(operand, &mut rng));
+        }
+        for precedence in [Precedence::Legacy, Precedence::Conventional] {
+            assert_matches(&expr, precedence, |inputs| {
+                let vals: Vec<bool> = operands.iter().map(|operand| eval_operand(operand, inputs)).collect();
+                eval_chain(&vals, &ops, precedence)
+            });
+        }
+    }
+}
+
+#[test]
+fn same_precedence_chains_group_from_the_left() {
+    let chains = [
+        ("A > B > C", "(A > B) > C"),
+        ("A >! B >! C", "(A >! B) >! C"),
+        ("A +! B +! C", "(A +! B) +! C"),
+        ("A *! B *! C", "(A *! B) *! C"),
+        ("A =! B = C", "(A =! B) = C"),
+        ("A @! B @ C", "(A @! B) @ C")
+    ];
+    for (chain, grouped) in chains {
+        assert_eq!(evaluate(chain, Precedence::Legacy), evaluate(grouped, Precedence::Legacy), "`{}`", chain);
+    }
+}
+
+#[test]
+fn conventional_implication_groups_from_the_right() {
+    let conventional = Precedence::Conventional;
+    assert_eq!(evaluate("A > B > C", conventional), evaluate("A > (B > C)", conventional));
+    assert_eq!(evaluate("A >! B > C", conventional), evaluate("A >! (B > C)", conventional));
+    assert_eq!(evaluate("A = B = C", conventional), evaluate("(A = B) = C", conventional));
+}
+
+#[test]
+fn profiles_rank_operators_differently() {
+    assert_eq!(evaluate("A + B = C", Precedence::Legacy), evaluate("A + (B = C)", Precedence::Legacy));
+    assert_eq!(evaluate("A + B = C", Precedence::Conventional), evaluate("(A + B) = C", Precedence::Conventional));
+    assert_eq!(evaluate("A * B + C", Precedence::Conventional), evaluate("(A * B) + C", Precedence::Conventional));
+}
+
+#[test]
+fn operator_spellings_match_symbols() {
+    let spellings = [
+        ("A && B", "A * B"), ("A and B", "A * B"), ("A ∧ B", "A * B"),
+        ("A || B", "A + B"), ("A or B", "A + B"), ("A ∨ B", "A + B"),
+        ("A ^ B", "A @ B"), ("A xor B", "A @ B"), ("A ⊕ B", "A @ B"),
+        ("A -> B", "A > B"), ("A implies B", "A > B"), ("A → B", "A > B"),
+        ("A <-> B", "A = B"), ("A iff B", "A = B"), ("A ↔ B", "A = B"),
+        ("not A", "~A"), ("¬A", "~A"), ("!A", "~A")
+    ];
+    for (spelling, symbol) in spellings {
+        assert_eq!(evaluate(spelling, Precedence::Legacy), evaluate(symbol, Precedence::Legacy), "`{}`", spelling);
+    }
+}
+
+#[test]
+fn bang_after_spellings_negates_the_operand() {
+    let negated = [
+        ("A&&!B", "A * ~B"), ("A||!B", "A + ~B"), ("A^!B", "A @ ~B"),
+        ("A->!B", "A > ~B"), ("A<->!B", "A = ~B"), ("A and !B", "A * ~B"),
+        ("A ∧!B", "A * ~B"), ("A →!B", "A > ~B")
+    ];
+    for (spelling, symbol) in negated {
+        assert_eq!(evaluate(spelling, Precedence::Legacy), evaluate(symbol, Precedence::Legacy), "`{}`", spelling);
+    }
+
+    // only the symbols are negated by a bang after them, with or without space between
+    let flipped = [("A+!B", "~(A + B)"), ("A*!B", "~(A * B)"), ("A>!B", "~(A > B)"), ("A + !B", "~(A + B)"), ("A @ ! B", "A = B")];
+    for (spelling, symbol) in flipped {
+        assert_eq!(evaluate(spelling, Precedence::Legacy), evaluate(symbol, Precedence::Legacy), "`{}`", spelling);
+    }
+}
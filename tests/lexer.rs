@@ -0,0 +1,165 @@
+#![allow(clippy::needless_return)]
+
+// uses
+use ttable::{ErrorKind, Script};
+use ttable::script::expr::Span;
+use ttable::script::parse::ParseOptions;
+use ttable::util::symbols::Symbols;
+use ttable::util::tokenize::*;
+use ttable::Value;
+
+/**
+ * parses a script, skipping unknown chars if lenient
+ */
+fn parse(source: &str, lenient: bool) -> ttable::error::Result<Script> {
+    let options = ParseOptions { lenient, ..ParseOptions::default() };
+    return Script::parse_with(source, &options);
+}
+
+#[test]
+fn unknown_chars_are_errors_with_positions() {
+    let cases = [
+        ("A, B, Z: A & B.", '&', 1, 12),
+        ("A, B,\nZ: A | B.", '|', 2, 6),
+        ("A, B,\r\nZ: A # B.", '#', 2, 6),
+        ("α, β, Z: α ☃ β.", '☃', 1, 12)
+    ];
+    for (source, c, line, col) in cases {
+        let error = parse(source, false).unwrap_err();
+        assert_eq!(error.kind, ErrorKind::UnexpectedChar(c), "`{}`", source);
+        assert_eq!((error.line, error.col), (line, col), "`{}`", source);
+        assert_eq!(&source[error.span.start..error.span.end], c.to_string(), "`{}`", source);
+    }
+}
+
+#[test]
+fn lenient_skips_unknown_chars() {
+    let lenient = parse("A, B, Z: A #* B.", true).unwrap().evaluate().unwrap();
+    let strict = parse("A, B, Z: A * B.", false).unwrap().evaluate().unwrap();
+    assert_eq!(lenient.rows, strict.rows);
+}
+
+#[test]
+fn comments_may_hold_any_chars() {
+    assert!(parse("A, % “quoted” & | #\nZ: A'. [ ☃ ]", false).is_ok());
+}
+
+#[test]
+fn identifiers_may_be_unicode() {
+    let script = parse("α, é_1, Größe: α * é_1.", false).unwrap();
+    let names: Vec<&str> = script.lines.iter().map(|line| line.name.as_str()).collect();
+    assert_eq!(names, ["α", "é_1", "Größe"]);
+}
+
+#[test]
+fn lexer_yields_tokens_with_byte_spans() {
+    let symbols = Symbols::default();
+    let source = "Größe: ~A +! (B -> 1)";
+    let tokens: Vec<(Token, Span)> = Lexer::new(source, &symbols).map(|token| {
+        let token = token.unwrap();
+        (token.node, token.span)
+    }).collect();
+    assert_eq!(tokens, [
+        (Token::Var("Größe"), Span::new(0, 7)),
+        (Token::Op(ASSIGNMENT), Span::new(7, 8)),
+        (Token::Op(PRE_NEGATION), Span::new(9, 10)),
+        (Token::Var("A"), Span::new(10, 11)),
+        (Token::Op(DISJUNCTION), Span::new(12, 13)),
+        (Token::Op(POST_OP_NEGATION), Span::new(13, 14)),
+        (Token::Op(OPEN), Span::new(15, 16)),
+        (Token::Var("B"), Span::new(16, 17)),
+        (Token::Op(IMPLICATION), Span::new(18, 20)),
+        (Token::Val(Value::True), Span::new(21, 22)),
+        (Token::Op(CLOSE), Span::new(22, 23))
+    ]);
+}
+
+#[test]
+fn lexer_borrows_variables_from_the_source() {
+    let symbols = Symbols::default();
+    let source = String::from("first, second");
+    let token = Lexer::new(&source, &symbols).nth(2).unwrap().unwrap();
+    match token.node {
+        Token::Var(var) => assert_eq!(var.as_ptr(), source[7..].as_ptr()),
+        token => panic!("expected a variable, not {:?}", token)
+    }
+}
+
+#[test]
+fn lexer_continues_after_errors() {
+    let symbols = Symbols::default();
+    let tokens: Vec<_> = Lexer::new("A ☃ B", &symbols).collect();
+    assert_eq!(tokens, [
+        Ok(Spanned { node: Token::Var("A"), span: Span::new(0, 1) }),
+        Err(LexError { c: '☃', span: Span::new(2, 5) }),
+        Ok(Spanned { node: Token::Var("B"), span: Span::new(6, 7) })
+    ]);
+}
+
+#[test]
+fn declared_variables_win_over_word_symbols() {
+    let options = ParseOptions { symbols: Symbols::from_name("letters").unwrap(), ..ParseOptions::default() };
+    let table = Script::parse_with("T, F, Z: T * F.", &options).unwrap().evaluate().unwrap();
+    assert_eq!(table.rows.len(), 4);
+    let table = Script::parse_with("A, Z: A * T + F.", &options).unwrap().evaluate().unwrap();
+    assert_eq!(table.rows, [[Value::False, Value::False], [Value::True, Value::True]]);
+}
+
+#[test]
+fn symbols_must_not_read_as_operators() {
+    for name in ["+,-", "yes,no,(", "1.0,0", "T,F,or", "->,<-", "1,0,-", "-,0", "1,<-", "y<,n", "1,0,&"] {
+        assert_eq!(Symbols::from_name(name), None, "`{}`", name);
+    }
+    for name in ["yes,no,maybe", "T1,F1", "⊤,⊥,?"] {
+        assert!(Symbols::from_name(name).is_some(), "`{}`", name);
+    }
+}
+
+#[test]
+fn pragmas_are_apart_from_comments() {
+    assert!(parse("%! TODO fix this\nA, B, Z: A + B.", false).is_ok());
+    let error = parse("%%! precedance conventional\nA.", false).unwrap_err();
+    assert_eq!(error.kind, ErrorKind::UnknownPragma(String::from("precedance conventional")));
+
+    // a pragma outranks the precedence given to the parser
+    let pragma = parse("%%! precedence conventional\nA, B, C, Z: A + B = C.", false).unwrap().evaluate().unwrap();
+    let grouped = parse("A, B, C, Z: (A + B) = C.", false).unwrap().evaluate().unwrap();
+    assert_eq!(pragma.rows, grouped.rows);
+}
+
+#[test]
+fn unicode_spaces_separate_tokens() {
+    let spaced = parse("A,\u{a0}B,\u{2003}Z:\u{a0}A\u{202f}*\u{3000}B.", false).unwrap().evaluate().unwrap();
+    let plain = parse("A, B, Z: A * B.", false).unwrap().evaluate().unwrap();
+    assert_eq!(spaced.rows, plain.rows);
+}
+
+#[test]
+fn lexer_yields_comments_as_tokens() {
+    let symbols = Symbols::default();
+    let source = "A % “rest” & |\n[ ☃\n] B [open";
+    let tokens: Vec<Token> = Lexer::new(source, &symbols).map(|token| token.unwrap().node).collect();
+    assert_eq!(tokens, [
+        Token::Var("A"),
+        Token::Comment("% “rest” & |"),
+        Token::Comment("[ ☃\n]"),
+        Token::Var("B"),
+        Token::Comment("[open")
+    ]);
+}
+
+#[test]
+fn line_names_leave_out_comments() {
+    let script = parse("A, B, A [and] *B % or\n, A[x]+ B.", false).unwrap();
+    let names: Vec<&str> = script.lines.iter().map(|line| line.name.as_str()).collect();
+    assert_eq!(names, ["A", "B", "A *B", "A + B"]);
+}
+
+#[test]
+fn pragmas_only_come_from_line_comments_in_the_script() {
+    assert!(parse("A, B, Z: A + B * A\n[\n%%! nonsense\n]\n.", false).is_ok());
+    assert!(parse("A, B, Z: A + B * A.\n%%! strict", false).is_ok());
+    assert!(parse("A, % %%! strict\nB, Z: A + B * A.", false).is_ok());
+    let error = parse("  %%! strict\nA, B, Z: A + B * A.", false).unwrap_err();
+    assert_eq!(error.kind, ErrorKind::MixedOperators("conjunction", "disjunction"));
+}
@@ -0,0 +1,118 @@
+#![allow(clippy::needless_return)]
+
+// uses
+use std::time::{Duration, Instant};
+use ttable::minimize::{minimize, Implicant};
+
+/**
+ * gets an implicant from a product written with 1, 0 and - for each input
+ */
+fn implicant(product: &str) -> Implicant {
+    let mut implicant = Implicant { bits: 0, mask: 0 };
+    for c in product.chars() {
+        implicant.bits <<= 1;
+        implicant.mask <<= 1;
+        match c {
+            '1' => implicant.bits |= 1,
+            '-' => implicant.mask |= 1,
+            _ => {}
+        }
+    }
+    return implicant;
+}
+
+/**
+ * gets implicants from products, sorted so covers can be compared
+ */
+fn implicants(products: &[&str]) -> Vec<Implicant> {
+    let mut implicants: Vec<Implicant> = products.iter().map(|product| implicant(product)).collect();
+    implicants.sort();
+    return implicants;
+}
+
+/**
+ * minimizes a function and gets its cover sorted
+ */
+fn cover(inputs: usize, minterms: &[u64], dont_cares: &[u64]) -> Vec<Implicant> {
+    let minimization = minimize(inputs, minterms, dont_cares);
+    assert!(minimization.exact);
+    let mut cover = minimization.cover;
+    cover.sort();
+    return cover;
+}
+
+#[test]
+fn essential_primes_cover_the_function() {
+
+    // A'B' + AC over minterms 000, 001, 101, 111
+    assert_eq!(cover(3, &[0, 1, 5, 7], &[]), implicants(&["00-", "1-1"]));
+}
+
+#[test]
+fn primes_are_found_with_their_masks() {
+    let minimization = minimize(3, &[0, 1, 2, 5, 6, 7], &[]);
+    let mut primes = minimization.primes;
+    primes.sort();
+    assert_eq!(primes, implicants(&["00-", "0-0", "-01", "-10", "1-1", "11-"]));
+}
+
+#[test]
+fn cyclic_cores_are_covered_by_petrick() {
+
+    // no prime is essential, and two covers of three primes are minimal
+    let minimization = minimize(3, &[0, 1, 2, 5, 6, 7], &[]);
+    assert!(minimization.exact);
+    assert_eq!(minimization.cover.len(), 3);
+    for minterm in [0, 1, 2, 5, 6, 7] {
+        assert!(minimization.cover.iter().any(|prime| prime.covers(minterm)), "{}", minterm);
+    }
+    for minterm in [3, 4] {
+        assert!(!minimization.cover.iter().any(|prime| prime.covers(minterm)), "{}", minterm);
+    }
+}
+
+#[test]
+fn dont_cares_widen_primes_without_being_covered() {
+
+    // BCD digits above 4, with 10 to 15 never happening
+    let dont_cares: Vec<u64> = (10..16).collect();
+    assert_eq!(cover(4, &[5, 6, 7, 8, 9], &dont_cares), implicants(&["1---", "-1-1", "-11-"]));
+
+    // a don't care alone needs no cover
+    assert_eq!(cover(2, &[], &[3]), implicants(&[]));
+    assert_eq!(cover(2, &[0], &[1]), implicants(&["0-"]));
+}
+
+#[test]
+fn covers_are_the_same_every_run() {
+
+    // (A @ B) + (B @ C) has two minimal covers of three primes
+    let first = minimize(3, &[1, 2, 3, 4, 5, 6], &[]);
+    for _ in 0..20 {
+        assert_eq!(minimize(3, &[1, 2, 3, 4, 5, 6], &[]), first);
+    }
+}
+
+#[test]
+fn constant_functions_need_no_literals() {
+    assert_eq!(cover(2, &[0, 1, 2, 3], &[]), implicants(&["--"]));
+    assert_eq!(cover(2, &[], &[]), implicants(&[]));
+}
+
+#[test]
+fn large_cyclic_cores_finish_quickly() {
+
+    // three independent cyclic cores of three inputs each, (A @ B) + (B @ C) over every triple
+    let minterms: Vec<u64> = (0..1 << 9).filter(|minterm: &u64| {
+        (0..3).any(|i| {
+            let triple = minterm >> (3 * i) & 0b111;
+            triple != 0 && triple != 0b111
+        })
+    }).collect();
+    let start = Instant::now();
+    let minimization = minimize(9, &minterms, &[]);
+    assert!(start.elapsed() < Duration::from_secs(1), "took {:?}", start.elapsed());
+    assert!(minimization.exact);
+    assert_eq!(minimization.cover.len(), 9);
+    assert_eq!(minimization.primes.len(), 18);
+}